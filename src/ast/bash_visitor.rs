@@ -0,0 +1,127 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct BashVisitor {
+    symbols: Vec<Symbol>,
+}
+
+impl BashVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+        }
+    }
+
+    fn extract_function(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..Symbol::new(SymbolKind::Function, name, node)
+        })
+    }
+
+    fn extract_assignment(&self, assignment: &Node, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let name_node = assignment.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: assignment.child_by_field_name("value").map(|n| n.byte_range()),
+            ..Symbol::new(kind, name, node)
+        })
+    }
+
+    /// `export A=1 B`, `readonly C=2`, `declare -a D=()`; `readonly` (or
+    /// `declare -r`) variables are reported as constants.
+    fn extract_declarations(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.children(&mut cursor).collect();
+
+        let keyword = children.first()
+            .and_then(|c| c.utf8_text(source.as_bytes()).ok())
+            .unwrap_or("");
+        if keyword == "local" {
+            return Vec::new();
+        }
+        let readonly = keyword == "readonly"
+            || children.iter().any(|c| c.kind() == "word" && c.utf8_text(source.as_bytes()) == Ok("-r"));
+        let kind = if readonly { SymbolKind::Constant } else { SymbolKind::Variable };
+
+        children.iter()
+            .filter_map(|child| match child.kind() {
+                "variable_assignment" => self.extract_assignment(child, node, source, kind),
+                "variable_name" => {
+                    let name = child.utf8_text(source.as_bytes()).ok()?.to_string();
+                    Some(Symbol {
+                        name_range: Some(child.byte_range()),
+                        ..Symbol::new(kind, name, node)
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// `source path` and `. path` become imports named after the sourced path.
+    fn extract_source(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let command = node.child_by_field_name("name")?.utf8_text(source.as_bytes()).ok()?;
+        if command != "source" && command != "." {
+            return None;
+        }
+        let path_node = node.child_by_field_name("argument")?;
+        let path = path_node.utf8_text(source.as_bytes()).ok()?;
+        let path = path.trim_matches(|c| c == '"' || c == '\'').to_string();
+
+        Some(Symbol {
+            name_range: Some(path_node.byte_range()),
+            ..Symbol::new(SymbolKind::Import, path, node)
+        })
+    }
+}
+
+impl LanguageVisitor for BashVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "function_definition" => {
+                // Assignments inside functions are locals, not script symbols
+                if let Some(symbol) = self.extract_function(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+                return;
+            }
+            "variable_assignment" => {
+                if let Some(symbol) = self.extract_assignment(node, node, source_code, SymbolKind::Variable) {
+                    self.symbols.push(symbol);
+                }
+                return;
+            }
+            "declaration_command" => {
+                let symbols = self.extract_declarations(node, source_code);
+                self.symbols.extend(symbols);
+                return;
+            }
+            "command" => {
+                if let Some(symbol) = self.extract_source(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        // Recurse into children
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,199 @@
+use super::preprocessor::{self, Preprocessor};
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+pub struct CVisitor {
+    symbols: Vec<Symbol>,
+    preprocessor: Preprocessor,
+}
+
+impl CVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            preprocessor: Preprocessor::new(None),
+        }
+    }
+
+    /// Skip the `#if` branches that a build with these macros defined
+    /// leaves out.
+    pub fn with_defines(mut self, defines: Option<HashMap<String, String>>) -> Self {
+        self.preprocessor = Preprocessor::new(defines);
+        self
+    }
+
+    /// Visit the compiled branches of an `#if` chain, recording the
+    /// condition of each on the symbols declared in it.
+    fn visit_branches(&mut self, node: &Node, source_code: &str) {
+        for branch in self.preprocessor.branches(*node, source_code) {
+            let start = self.symbols.len();
+            for child in &branch.body {
+                self.visit(child, source_code);
+            }
+            if let Some(condition) = &branch.condition {
+                preprocessor::set_condition(&mut self.symbols[start..], condition);
+            }
+        }
+    }
+
+    /// Follow nested `declarator` fields (pointers, arrays, parentheses,
+    /// initializers) down to the identifier being declared.
+    pub fn declarator_name<'a>(declarator: Node<'a>) -> Option<Node<'a>> {
+        match declarator.kind() {
+            "identifier" | "type_identifier" | "field_identifier" | "primitive_type" => Some(declarator),
+            "parenthesized_declarator" => {
+                let inner = declarator.named_child(0)?;
+                Self::declarator_name(inner)
+            }
+            _ => Self::declarator_name(declarator.child_by_field_name("declarator")?),
+        }
+    }
+
+    /// A declarator declares a function (rather than e.g. a function pointer
+    /// variable) when its outermost non-pointer layer is a function declarator
+    /// wrapping a plain identifier.
+    fn is_function_declarator(declarator: Node) -> bool {
+        match declarator.kind() {
+            "function_declarator" => declarator
+                .child_by_field_name("declarator")
+                .is_some_and(|inner| inner.kind() == "identifier"),
+            "pointer_declarator" => declarator
+                .child_by_field_name("declarator")
+                .is_some_and(Self::is_function_declarator),
+            _ => false,
+        }
+    }
+
+    fn extract_function(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let declarator = node.child_by_field_name("declarator")?;
+        let name_node = Self::declarator_name(declarator)?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..Symbol::new(SymbolKind::Function, name, node)
+        })
+    }
+
+    /// Named `struct`, `union` and `enum` definitions. Bare references such as
+    /// `struct point p;` have no body and are skipped.
+    fn extract_tagged_type(&self, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let body = node.child_by_field_name("body")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: Some(body.byte_range()),
+            ..Symbol::new(kind, name, node)
+        })
+    }
+
+    /// `typedef` may introduce several names at once (`typedef int a, *b;`).
+    fn extract_typedefs(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut symbols = Vec::new();
+        let mut cursor = node.walk();
+        for declarator in node.children_by_field_name("declarator", &mut cursor) {
+            let Some(name_node) = Self::declarator_name(declarator) else {
+                continue;
+            };
+            let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                continue;
+            };
+            symbols.push(Symbol {
+                name_range: Some(name_node.byte_range()),
+                ..Symbol::new(SymbolKind::Type, name.to_string(), node)
+            });
+        }
+        symbols
+    }
+
+    /// Top-level declarations are either function prototypes or variables.
+    fn extract_declarations(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut symbols = Vec::new();
+        let mut cursor = node.walk();
+        for declarator in node.children_by_field_name("declarator", &mut cursor) {
+            let Some(name_node) = Self::declarator_name(declarator) else {
+                continue;
+            };
+            let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                continue;
+            };
+            let kind = if Self::is_function_declarator(declarator) {
+                SymbolKind::Function
+            } else {
+                SymbolKind::Variable
+            };
+            symbols.push(Symbol {
+                name_range: Some(name_node.byte_range()),
+                ..Symbol::new(kind, name.to_string(), node)
+            });
+        }
+        symbols
+    }
+}
+
+impl LanguageVisitor for CVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "function_definition" => {
+                if let Some(symbol) = self.extract_function(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+                // C has no nested functions; locals are not symbols.
+                return;
+            }
+            "struct_specifier" => {
+                if let Some(symbol) = self.extract_tagged_type(node, source_code, SymbolKind::Struct) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "union_specifier" => {
+                if let Some(symbol) = self.extract_tagged_type(node, source_code, SymbolKind::Union) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "enum_specifier" => {
+                if let Some(symbol) = self.extract_tagged_type(node, source_code, SymbolKind::Enum) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "type_definition" => {
+                let symbols = self.extract_typedefs(node, source_code);
+                self.symbols.extend(symbols);
+            }
+            "declaration" => {
+                let symbols = self.extract_declarations(node, source_code);
+                self.symbols.extend(symbols);
+            }
+            "preproc_def" | "preproc_function_def" => {
+                self.preprocessor.define(node, source_code);
+                self.symbols.extend(preprocessor::macro_symbol(node, source_code));
+                return;
+            }
+            "preproc_call" => {
+                self.preprocessor.undefine(node, source_code);
+                return;
+            }
+            "preproc_if" | "preproc_ifdef" => {
+                self.visit_branches(node, source_code);
+                return;
+            }
+            _ => {}
+        }
+
+        // Recurse into children
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
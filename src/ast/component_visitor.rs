@@ -0,0 +1,102 @@
+use super::html_visitor::HtmlVisitor;
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct ComponentVisitor {
+    symbols: Vec<Symbol>,
+    /// Name of the component, the file name without its extension.
+    component: String,
+}
+
+impl ComponentVisitor {
+    pub fn new(component: &str) -> Self {
+        Self {
+            symbols: Vec::new(),
+            component: component.to_string(),
+        }
+    }
+
+    /// Regions are code blocks named after their language, so that the
+    /// script can be parsed in turn like a Markdown code fence.
+    fn region(&self, node: &Node, source: &str, default_lang: &str, content: std::ops::Range<usize>) -> Option<Symbol> {
+        let lang = HtmlVisitor::attribute(node, source, "lang");
+        let name = match lang {
+            Some(lang) => lang.utf8_text(source.as_bytes()).ok()?.to_string(),
+            None => default_lang.to_string(),
+        };
+
+        Some(Symbol {
+            name_range: lang.map(|n| n.byte_range()),
+            body_range: Some(content),
+            container: Some(self.component.clone()),
+            ..Symbol::new(SymbolKind::CodeBlock, name, node)
+        })
+    }
+
+    /// `<script>` and `<style>` hold their content as raw text.
+    fn extract_raw_region(&self, node: &Node, source: &str, default_lang: &str) -> Option<Symbol> {
+        let mut cursor = node.walk();
+        let content = node.children(&mut cursor).find(|c| c.kind() == "raw_text")?;
+        self.region(node, source, default_lang, content.byte_range())
+    }
+
+    /// A Vue `<template>`, covering everything between its tags.
+    fn extract_template(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let start_tag = node.child(0)?;
+        let end_tag = node.child(node.child_count().checked_sub(1)?).filter(|c| c.kind() == "end_tag")?;
+        self.region(node, source, "html", start_tag.end_byte()..end_tag.start_byte())
+    }
+
+    /// Svelte markup sits directly at the top level, between the script and
+    /// style blocks; it is reported as one region from its first node to its
+    /// last.
+    fn extract_markup(&self, markup: &[Node]) -> Option<Symbol> {
+        let (first, last) = (markup.first()?, markup.last()?);
+        Some(Symbol {
+            start_line: first.start_position().row + 1,
+            end_line: last.end_position().row + 1,
+            full_range: first.start_byte()..last.end_byte(),
+            body_range: Some(first.start_byte()..last.end_byte()),
+            container: Some(self.component.clone()),
+            ..Symbol::new(SymbolKind::CodeBlock, "html".to_string(), first)
+        })
+    }
+}
+
+impl LanguageVisitor for ComponentVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        // The component spans the whole file, up to its last line with content
+        self.symbols.push(Symbol {
+            end_line: source_code.trim_end().matches('\n').count() + 1,
+            qualified_name: Some(self.component.clone()),
+            ..Symbol::new(SymbolKind::Class, self.component.clone(), node)
+        });
+
+        let mut regions = Vec::new();
+        let mut markup = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            let region = match child.kind() {
+                "script_element" => self.extract_raw_region(&child, source_code, "js"),
+                "style_element" => self.extract_raw_region(&child, source_code, "css"),
+                "element" if HtmlVisitor::tag_name(&child, source_code) == Some("template") => {
+                    self.extract_template(&child, source_code)
+                }
+                "comment" => None,
+                _ => {
+                    markup.push(child);
+                    None
+                }
+            };
+            regions.extend(region);
+        }
+        regions.extend(self.extract_markup(&markup));
+        regions.sort_by_key(|region| region.full_range.start);
+        self.symbols.extend(regions);
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -1,130 +1,583 @@
-use super::symbol::{Symbol, SymbolKind};
+use super::c_visitor::CVisitor;
+use super::preprocessor::{self, Preprocessor};
+use super::symbol::{Symbol, SymbolKind, Visibility};
 use super::visitor::LanguageVisitor;
+use std::collections::{HashMap, HashSet};
 use tree_sitter::Node;
 
 pub struct CppVisitor {
     symbols: Vec<Symbol>,
+    /// Enclosing namespaces and classes, outermost first.
+    scope: Vec<String>,
+    /// Whether the innermost scope is a class, struct or union body.
+    in_class: bool,
+    /// Qualified names of the namespaces seen so far, to tell an out-of-line
+    /// `ns::helper()` function from a `Foo::bar()` method.
+    namespaces: HashSet<String>,
+    preprocessor: Preprocessor,
 }
 
 impl CppVisitor {
     pub fn new() -> Self {
         Self {
             symbols: Vec::new(),
+            scope: Vec::new(),
+            in_class: false,
+            namespaces: HashSet::new(),
+            preprocessor: Preprocessor::new(None),
         }
     }
 
-    fn extract_function(&self, node: &Node, source: &str) -> Option<Symbol> {
-        let declarator = node.child_by_field_name("declarator")?;
-        let func_declarator = declarator.child_by_field_name("declarator")
-            .unwrap_or(declarator);
-        
-        let name = func_declarator.utf8_text(source.as_bytes()).ok()?.to_string();
-        
-        Some(Symbol {
-            kind: SymbolKind::Function,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
-            name_range: Some(func_declarator.byte_range()),
+    /// Skip the `#if` branches that a build with these macros defined
+    /// leaves out. `__cplusplus` is always defined.
+    pub fn with_defines(mut self, defines: Option<HashMap<String, String>>) -> Self {
+        self.preprocessor = Preprocessor::new(defines.map(|mut defines| {
+            defines.entry("__cplusplus".to_string()).or_insert_with(|| "201703L".to_string());
+            defines
+        }));
+        self
+    }
+
+    /// Visit the compiled branches of an `#if` chain, recording the
+    /// condition of each on the symbols declared in it.
+    fn visit_branches(&mut self, node: &Node, source_code: &str) {
+        for branch in self.preprocessor.branches(*node, source_code) {
+            let start = self.symbols.len();
+            for child in &branch.body {
+                self.visit(child, source_code);
+            }
+            if let Some(condition) = &branch.condition {
+                preprocessor::set_condition(&mut self.symbols[start..], condition);
+            }
+        }
+    }
+
+    /// Place `symbol` in the current scope, followed by `qualifier` for
+    /// out-of-line definitions such as `Foo::bar`.
+    fn scoped(&self, symbol: Symbol, qualifier: &[String]) -> Symbol {
+        let mut path = self.scope.clone();
+        path.extend(qualifier.iter().cloned());
+        let container = if path.is_empty() { None } else { Some(path.join("::")) };
+        let qualified_name = match &container {
+            Some(container) => format!("{}::{}", container, symbol.name),
+            None => symbol.name.clone(),
+        };
+        Symbol {
+            container,
+            qualified_name: Some(qualified_name),
+            ..symbol
+        }
+    }
+
+    /// The `template <...>` declarations wrapping `node` are part of it:
+    /// the symbol starts at the outermost one and records their parameters.
+    fn with_template(mut symbol: Symbol, node: &Node, source: &str) -> Symbol {
+        let mut parameters = Vec::new();
+        let mut parent = node.parent();
+        while let Some(template) = parent.filter(|p| p.kind() == "template_declaration") {
+            if let Some(list) = template.child_by_field_name("parameters")
+                .and_then(|list| list.utf8_text(source.as_bytes()).ok())
+            {
+                parameters.push(list.to_string());
+            }
+            symbol.start_line = template.start_position().row + 1;
+            symbol.full_range.start = template.start_byte();
+            parent = template.parent();
+        }
+        if !parameters.is_empty() {
+            parameters.reverse();
+            symbol.type_parameters = Some(parameters.join(" "));
+        }
+        symbol
+    }
+
+    /// The node declaring `node` among its siblings, outside any `template`
+    /// declarations wrapping it.
+    fn declaration<'a>(node: &Node<'a>) -> Node<'a> {
+        let mut node = *node;
+        while let Some(template) = node.parent().filter(|p| p.kind() == "template_declaration") {
+            node = template;
+        }
+        node
+    }
+
+    /// Name of a function declarator, below any pointer or reference return
+    /// type: an identifier, `Foo::bar`, `~Foo`, `operator==` or `operator
+    /// bool`. `None` for function pointers and other declarators.
+    fn function_name(declarator: Node) -> Option<Node> {
+        match declarator.kind() {
+            "pointer_declarator" | "reference_declarator" | "init_declarator" => declarator
+                .child_by_field_name("declarator")
+                .or_else(|| declarator.named_child(0))
+                .and_then(Self::function_name),
+            "function_declarator" => declarator
+                .child_by_field_name("declarator")
+                .filter(|inner| inner.kind() != "parenthesized_declarator"),
+            "operator_cast" => Some(declarator),
+            "qualified_identifier" => {
+                let mut name = declarator;
+                while name.kind() == "qualified_identifier" {
+                    name = name.child_by_field_name("name")?;
+                }
+                (name.kind() == "operator_cast").then_some(declarator)
+            }
+            _ => None,
+        }
+    }
+
+    /// Split `a::Foo<T>::bar` into its qualifier, without template
+    /// arguments, and the node of the final name.
+    fn split_qualified<'a>(mut node: Node<'a>, source: &str) -> (Vec<String>, Node<'a>) {
+        let mut qualifier = Vec::new();
+        while node.kind() == "qualified_identifier" {
+            if let Some(scope) = node.child_by_field_name("scope") {
+                let scope = match scope.kind() {
+                    "template_type" => scope.child_by_field_name("name").unwrap_or(scope),
+                    _ => scope,
+                };
+                if let Ok(text) = scope.utf8_text(source.as_bytes()) {
+                    qualifier.push(text.to_string());
+                }
+            }
+            let Some(name) = node.child_by_field_name("name") else {
+                break;
+            };
+            node = name;
+        }
+        // Explicit specializations, `swap<int>`, are named after the template
+        if node.kind() == "template_function"
+            && let Some(name) = node.child_by_field_name("name")
+        {
+            node = name;
+        }
+        (qualifier, node)
+    }
+
+    /// `operator bool() const` is named up to its parameters, the other
+    /// names as written with whitespace collapsed (`operator ==`).
+    fn function_name_text(name_node: &Node, source: &str) -> Option<String> {
+        let end = match name_node.kind() {
+            "operator_cast" => name_node.child_by_field_name("declarator").map_or(name_node.end_byte(), |d| d.start_byte()),
+            _ => name_node.end_byte(),
+        };
+        let text = source.get(name_node.start_byte()..end)?;
+        Some(text.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+
+    /// A function definition or declaration: constructors and destructors
+    /// are named after their class, operators are kinds of their own, and
+    /// functions of a class are methods. Out-of-line definitions, `void
+    /// Foo::bar() {}`, belong to the class they qualify unless it is a
+    /// namespace of this file, and take the access of their declaration.
+    fn extract_function(&self, node: &Node, declarator: Node, source: &str) -> Option<Symbol> {
+        let name_node = Self::function_name(declarator)?;
+        let (qualifier, name_node) = Self::split_qualified(name_node, source);
+        let name = Self::function_name_text(&name_node, source)?;
+
+        let class = if !qualifier.is_empty() {
+            let mut path = self.scope.clone();
+            path.extend(qualifier.iter().cloned());
+            (!self.namespaces.contains(&path.join("::"))).then(|| qualifier.last().cloned()).flatten()
+        } else if self.in_class {
+            self.scope.last().cloned()
+        } else {
+            None
+        };
+        let kind = match name_node.kind() {
+            "destructor_name" => SymbolKind::Destructor,
+            "operator_name" | "operator_cast" => SymbolKind::Operator,
+            _ if class.as_deref() == Some(name.as_str()) => SymbolKind::Constructor,
+            _ if class.is_some() => SymbolKind::Method,
+            _ => SymbolKind::Function,
+        };
+
+        let symbol = Symbol {
+            name_range: Some(name_node.byte_range()),
             body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
-        })
+            ..Symbol::new(kind, name, node)
+        };
+        let mut symbol = self.scoped(Self::with_template(symbol, node, source), &qualifier);
+        symbol.decorators = Self::attributes(node, source);
+        symbol.visibility = Some(Self::visibility(node, source));
+        if !qualifier.is_empty()
+            && let Some(declared) = self.symbols.iter().rev()
+                .find(|s| s.body_range.is_none() && s.qualified_name == symbol.qualified_name)
+        {
+            symbol.visibility = declared.visibility;
+        }
+        Some(symbol)
     }
 
     fn extract_class(&self, node: &Node, source: &str) -> Option<Symbol> {
         let name_node = node.child_by_field_name("name")?;
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
-        
+
         Some(Symbol {
-            kind: SymbolKind::Class,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
             body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..Symbol::new(SymbolKind::Class, name, node)
         })
     }
 
     fn extract_struct(&self, node: &Node, source: &str) -> Option<Symbol> {
         let name_node = node.child_by_field_name("name")?;
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
-        
+
         Some(Symbol {
-            kind: SymbolKind::Struct,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
             body_range: node.child_by_field_name("body").map(|b| b.byte_range()),
+            ..Symbol::new(SymbolKind::Struct, name, node)
         })
     }
 
-    fn extract_variable(&self, node: &Node, source: &str) -> Option<Symbol> {
-        let declarator = node.child_by_field_name("declarator")?;
-        
-        // Try to find the identifier within the declarator
-        let name = if let Some(identifier) = declarator.child_by_field_name("declarator") {
-            // Handle cases like "auto variable_name = ..."
-            identifier.utf8_text(source.as_bytes()).ok()?.to_string()
-        } else if declarator.kind() == "identifier" {
-            // Direct identifier
-            declarator.utf8_text(source.as_bytes()).ok()?.to_string()
-        } else {
-            // Fallback: look for first identifier child
-            let mut cursor = declarator.walk();
-            cursor.goto_first_child();
-            loop {
-                let current = cursor.node();
-                if current.kind() == "identifier" {
-                    return Some(Symbol {
-                        kind: SymbolKind::Variable,
-                        name: current.utf8_text(source.as_bytes()).ok()?.to_string(),
-                        start_line: node.start_position().row + 1,
-                        end_line: node.end_position().row + 1,
-                        full_range: node.byte_range(),
-                        name_range: Some(current.byte_range()),
-                        body_range: None,
-                    });
+    /// `enum` and `enum class` definitions; references such as
+    /// `enum Color c;` have no body and are skipped.
+    fn extract_enum(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let body = node.child_by_field_name("body")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: Some(body.byte_range()),
+            ..Symbol::new(SymbolKind::Enum, name, node)
+        })
+    }
+
+    /// `namespace a::b { }`, which is the container of its declarations.
+    /// Anonymous namespaces are not symbols, and only make their
+    /// declarations private to the file.
+    fn extract_namespace(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?;
+        let name = name.split_whitespace().collect::<String>();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|b| b.byte_range()),
+            ..Symbol::new(SymbolKind::Namespace, name, node)
+        })
+    }
+
+    /// `[[nodiscard]]` and `[[deprecated("use g")]]` attributes of a
+    /// declaration, one per attribute even when they share brackets.
+    fn attributes(node: &Node, source: &str) -> Vec<String> {
+        let mut attributes = Vec::new();
+        let mut cursor = node.walk();
+        for declaration in node.children(&mut cursor).filter(|c| c.kind() == "attribute_declaration") {
+            let mut cursor = declaration.walk();
+            for attribute in declaration.named_children(&mut cursor) {
+                if let Ok(text) = attribute.utf8_text(source.as_bytes()) {
+                    attributes.push(text.to_string());
                 }
-                if !cursor.goto_next_sibling() {
-                    break;
+            }
+        }
+        attributes
+    }
+
+    /// Access of a class member: that of the last `public:`, `protected:` or
+    /// `private:` before it, or else private in a class and public in a
+    /// struct or union. Outside classes, `static` declarations and those in
+    /// anonymous namespaces are private to their file and everything else
+    /// is public.
+    fn visibility(node: &Node, source: &str) -> Visibility {
+        // Nested types are specifiers within a member declaration
+        let node = match node.parent() {
+            Some(declaration) if declaration.kind() == "field_declaration" => declaration,
+            _ => Self::declaration(node),
+        };
+        let Some(body) = node.parent().filter(|p| p.kind() == "field_declaration_list") else {
+            let mut cursor = node.walk();
+            let is_static = node.children(&mut cursor).any(|c| {
+                c.kind() == "storage_class_specifier" && c.utf8_text(source.as_bytes()) == Ok("static")
+            });
+            let mut ancestor = node.parent();
+            let mut in_anonymous_namespace = false;
+            while let Some(parent) = ancestor {
+                if parent.kind() == "namespace_definition" && parent.child_by_field_name("name").is_none() {
+                    in_anonymous_namespace = true;
                 }
+                ancestor = parent.parent();
             }
-            declarator.utf8_text(source.as_bytes()).ok()?.to_string()
+            return if is_static || in_anonymous_namespace { Visibility::Private } else { Visibility::Public };
         };
-        
+
+        let mut sibling = node.prev_sibling();
+        while let Some(previous) = sibling {
+            if previous.kind() == "access_specifier" {
+                return match previous.utf8_text(source.as_bytes()).unwrap_or_default().trim_end_matches(':').trim() {
+                    "public" => Visibility::Public,
+                    "protected" => Visibility::Protected,
+                    _ => Visibility::Private,
+                };
+            }
+            sibling = previous.prev_sibling();
+        }
+        match body.parent().map(|p| p.kind()) {
+            Some("class_specifier") => Visibility::Private,
+            _ => Visibility::Public,
+        }
+    }
+
+    fn member(&self, kind: SymbolKind, name_node: Node, source: &str, node: &Node) -> Option<Symbol> {
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        let symbol = Symbol {
+            name_range: Some(name_node.byte_range()),
+            decorators: Self::attributes(node, source),
+            visibility: Some(Self::visibility(node, source)),
+            ..Symbol::new(kind, name, node)
+        };
+        Some(self.scoped(symbol, &[]))
+    }
+
+    /// A member declaration of a class body, one symbol per declarator
+    /// (`double a, *b;`). Member functions are declared methods; of the
+    /// data members, `static const` and `static constexpr` ones are
+    /// constants and the others fields, function pointers included.
+    fn extract_field_declaration(&self, declaration: &Node, source: &str) -> Vec<Symbol> {
+        let mut is_static = false;
+        let mut is_const = false;
+        let mut cursor = declaration.walk();
+        for child in declaration.children(&mut cursor) {
+            match child.utf8_text(source.as_bytes()) {
+                Ok("static") if child.kind() == "storage_class_specifier" => is_static = true,
+                Ok("const" | "constexpr") if child.kind() == "type_qualifier" => is_const = true,
+                _ => {}
+            }
+        }
+        let kind = if is_static && is_const { SymbolKind::Constant } else { SymbolKind::Field };
+
+        let mut members = Vec::new();
+        let mut cursor = declaration.walk();
+        for declarator in declaration.children_by_field_name("declarator", &mut cursor) {
+            if Self::function_name(declarator).is_some() {
+                members.extend(self.extract_function(declaration, declarator, source));
+                continue;
+            }
+            let member = CVisitor::declarator_name(declarator)
+                .and_then(|name_node| self.member(kind, name_node, source, declaration));
+            members.extend(member);
+        }
+        members
+    }
+
+    /// Enumerators of an enum body, `Mode::Fast`.
+    fn extract_enumerators(&self, node: &Node, source: &str, container: &str) -> Vec<Symbol> {
+        let Some(body) = node.child_by_field_name("body") else {
+            return Vec::new();
+        };
+        let mut cursor = body.walk();
+        body.named_children(&mut cursor)
+            .filter(|c| c.kind() == "enumerator")
+            .filter_map(|enumerator| {
+                let name_node = enumerator.child_by_field_name("name")?;
+                let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+                Some(Symbol {
+                    name_range: Some(name_node.byte_range()),
+                    container: Some(container.to_string()),
+                    qualified_name: Some(format!("{}::{}", container, name)),
+                    decorators: Self::attributes(&enumerator, source),
+                    visibility: Some(Self::visibility(&enumerator, source)),
+                    ..Symbol::new(SymbolKind::EnumMember, name, &enumerator)
+                })
+            })
+            .collect()
+    }
+
+    /// `using Id = int;`, including alias templates.
+    fn extract_alias(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..Symbol::new(SymbolKind::Type, name, node)
+        })
+    }
+
+    /// `typedef` may introduce several names at once, as in C.
+    fn extract_typedefs(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut symbols = Vec::new();
+        let mut cursor = node.walk();
+        for declarator in node.children_by_field_name("declarator", &mut cursor) {
+            let Some(name_node) = CVisitor::declarator_name(declarator) else {
+                continue;
+            };
+            let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                continue;
+            };
+            symbols.push(Symbol {
+                name_range: Some(name_node.byte_range()),
+                ..Symbol::new(SymbolKind::Type, name.to_string(), node)
+            });
+        }
+        symbols
+    }
+
+    fn extract_variable(&self, node: &Node, declarator: Node, source: &str) -> Option<Symbol> {
+        let name_node = Self::variable_name(declarator);
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
         Some(Symbol {
-            kind: SymbolKind::Variable,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
-            name_range: Some(declarator.byte_range()),
-            body_range: None,
+            name_range: Some(name_node.byte_range()),
+            ..Symbol::new(SymbolKind::Variable, name, node)
         })
     }
+
+    /// Name of a variable declarator below any initializer, pointer,
+    /// reference, array or parentheses: `fp` in `auto (*fp)(int) -> int;`.
+    /// Qualified names and structured bindings are kept whole.
+    fn variable_name(declarator: Node) -> Node {
+        match declarator.kind() {
+            "init_declarator" | "pointer_declarator" | "reference_declarator" | "array_declarator"
+            | "function_declarator" | "parenthesized_declarator" => declarator
+                .child_by_field_name("declarator")
+                .or_else(|| declarator.named_child(0))
+                .map_or(declarator, Self::variable_name),
+            _ => declarator,
+        }
+    }
+
+    /// Visit the members of a namespace or class named `name`.
+    fn visit_scope(&mut self, node: &Node, source_code: &str, name: Option<String>, is_class: bool) {
+        let depth = self.scope.len();
+        self.scope.extend(name);
+        let in_class = std::mem::replace(&mut self.in_class, is_class);
+        self.visit_children(node, source_code);
+        self.scope.truncate(depth);
+        self.in_class = in_class;
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
 }
 
 impl LanguageVisitor for CppVisitor {
     fn visit(&mut self, node: &Node, source_code: &str) {
         let maybe_symbol = match node.kind() {
-            "function_definition" => self.extract_function(node, source_code),
+            "namespace_definition" => {
+                let symbol = self.extract_namespace(node, source_code)
+                    .map(|symbol| self.scoped(symbol, &[]));
+                let name = symbol.as_ref().map(|symbol| symbol.name.clone());
+                if let Some(mut symbol) = symbol {
+                    symbol.visibility = Some(Self::visibility(node, source_code));
+                    self.namespaces.extend(symbol.qualified_name.clone());
+                    self.symbols.push(symbol);
+                }
+                self.visit_scope(node, source_code, name, false);
+                return;
+            }
+            "function_definition" => {
+                if let Some(declarator) = node.child_by_field_name("declarator")
+                    && let Some(symbol) = self.extract_function(node, declarator, source_code)
+                {
+                    self.symbols.push(symbol);
+                }
+                // Locals are not members of the enclosing namespace or class
+                let scope = std::mem::take(&mut self.scope);
+                self.visit_scope(node, source_code, None, false);
+                self.scope = scope;
+                return;
+            }
+            "field_declaration" if self.in_class => {
+                let members = self.extract_field_declaration(node, source_code);
+                self.symbols.extend(members);
+                // Nested types are declared within member declarations
+                self.visit_children(node, source_code);
+                return;
+            }
+            "declaration" => {
+                // One symbol per declarator (`int *make(int), global = 3;`):
+                // prototypes, constructors and destructors declared in a
+                // class body, which have no return type, and variables
+                let mut has_function = false;
+                let declarators: Vec<Node> = {
+                    let mut cursor = node.walk();
+                    node.children_by_field_name("declarator", &mut cursor).collect()
+                };
+                for declarator in declarators {
+                    if Self::function_name(declarator).is_some() {
+                        has_function = true;
+                        let function = self.extract_function(node, declarator, source_code);
+                        self.symbols.extend(function);
+                    } else if !self.in_class
+                        && let Some(variable) = self.extract_variable(node, declarator, source_code)
+                    {
+                        let mut symbol = self.scoped(Self::with_template(variable, node, source_code), &[]);
+                        symbol.decorators = Self::attributes(node, source_code);
+                        symbol.visibility = Some(Self::visibility(node, source_code));
+                        self.symbols.push(symbol);
+                    }
+                }
+                if !has_function {
+                    self.visit_children(node, source_code);
+                }
+                return;
+            }
+            // Friends are not members of the class befriending them
+            "friend_declaration" => return,
+            "preproc_def" | "preproc_function_def" => {
+                self.preprocessor.define(node, source_code);
+                if let Some(mut symbol) = preprocessor::macro_symbol(node, source_code) {
+                    // Macros ignore namespaces and are visible to every includer
+                    symbol.visibility = Some(Visibility::Public);
+                    self.symbols.push(symbol);
+                }
+                return;
+            }
+            "preproc_call" => {
+                self.preprocessor.undefine(node, source_code);
+                return;
+            }
+            "preproc_if" | "preproc_ifdef" => {
+                self.visit_branches(node, source_code);
+                return;
+            }
             "class_specifier" => self.extract_class(node, source_code),
             "struct_specifier" => self.extract_struct(node, source_code),
-            "declaration" => self.extract_variable(node, source_code),
+            "enum_specifier" => self.extract_enum(node, source_code),
+            "alias_declaration" => self.extract_alias(node, source_code),
+            "type_definition" => {
+                let visibility = Self::visibility(node, source_code);
+                let symbols: Vec<_> = self.extract_typedefs(node, source_code).into_iter()
+                    .map(|symbol| Symbol { visibility: Some(visibility), ..self.scoped(symbol, &[]) })
+                    .collect();
+                self.symbols.extend(symbols);
+                None
+            }
             _ => None,
         };
 
-        if let Some(symbol) = maybe_symbol {
-            self.symbols.push(symbol);
-        }
+        let Some(symbol) = maybe_symbol else {
+            self.visit_children(node, source_code);
+            return;
+        };
+        let mut symbol = self.scoped(Self::with_template(symbol, node, source_code), &[]);
+        symbol.decorators = Self::attributes(node, source_code);
+        symbol.visibility = Some(Self::visibility(node, source_code));
 
-        // Recurse into children
-        for i in 0..node.child_count() {
-            if let Some(child) = node.child(i) {
-                self.visit(&child, source_code);
+        match node.kind() {
+            "class_specifier" | "struct_specifier" => {
+                let name = symbol.name.clone();
+                self.symbols.push(symbol);
+                self.visit_scope(node, source_code, Some(name), true);
+            }
+            "enum_specifier" => {
+                // Enumerators follow the enum they belong to
+                let container = symbol.qualified_name.clone().unwrap_or_else(|| symbol.name.clone());
+                let enumerators = self.extract_enumerators(node, source_code, &container);
+                self.symbols.push(symbol);
+                self.symbols.extend(enumerators);
+            }
+            _ => {
+                self.symbols.push(symbol);
+                self.visit_children(node, source_code);
             }
         }
     }
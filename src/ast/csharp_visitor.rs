@@ -0,0 +1,184 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct CSharpVisitor {
+    symbols: Vec<Symbol>,
+    /// Enclosing namespaces and types, outermost first.
+    scope: Vec<String>,
+}
+
+impl CSharpVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+        }
+    }
+
+    fn container(&self) -> Option<String> {
+        visitor::scope_container(&self.scope, ".")
+    }
+
+    fn scoped_symbol(&self, kind: SymbolKind, name_node: &Node, node: &Node, source: &str) -> Option<Symbol> {
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(kind, name, self.container(), ".", node)
+        })
+    }
+
+    fn extract_named(&self, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let mut symbol = self.scoped_symbol(kind, &name_node, node, source)?;
+        symbol.partial = Self::has_modifier(node, source, "partial");
+        Some(symbol)
+    }
+
+    /// `~Order()` is named with its tilde, as C++ destructors are, so it
+    /// stands apart from the constructor.
+    fn extract_destructor(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = format!("~{}", name_node.utf8_text(source.as_bytes()).ok()?);
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(SymbolKind::Destructor, name, self.container(), ".", node)
+        })
+    }
+
+    fn has_modifier(node: &Node, source: &str, modifier: &str) -> bool {
+        let mut cursor = node.walk();
+        node.children(&mut cursor)
+            .filter(|child| child.kind() == "modifier")
+            .any(|child| child.utf8_text(source.as_bytes()) == Ok(modifier))
+    }
+
+    /// `int x, y;` and `event EventHandler A, B;` declare one symbol per
+    /// variable declarator, each spanning the whole declaration.
+    fn extract_variables(&self, node: &Node, source: &str, kind: SymbolKind) -> Vec<Symbol> {
+        let mut cursor = node.walk();
+        let Some(declaration) = node.children(&mut cursor).find(|c| c.kind() == "variable_declaration") else {
+            return Vec::new();
+        };
+
+        let mut cursor = declaration.walk();
+        declaration.children(&mut cursor)
+            .filter(|c| c.kind() == "variable_declarator")
+            .filter_map(|declarator| {
+                let name_node = declarator.child_by_field_name("name")?;
+                self.scoped_symbol(kind, &name_node, node, source)
+            })
+            .collect()
+    }
+
+    fn type_kind(node: &Node) -> Option<SymbolKind> {
+        match node.kind() {
+            "class_declaration" => Some(SymbolKind::Class),
+            "struct_declaration" => Some(SymbolKind::Struct),
+            "record_declaration" => Some(SymbolKind::Record),
+            "interface_declaration" => Some(SymbolKind::Interface),
+            "enum_declaration" => Some(SymbolKind::Enum),
+            _ => None,
+        }
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for CSharpVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        if let Some(kind) = Self::type_kind(node) {
+            if let Some(symbol) = self.extract_named(node, source_code, kind) {
+                self.scope.push(symbol.name.clone());
+                self.symbols.push(symbol);
+                self.visit_children(node, source_code);
+                self.scope.pop();
+            }
+            return;
+        }
+
+        match node.kind() {
+            "namespace_declaration" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Namespace) {
+                    let depth = self.scope.len();
+                    self.scope.push(symbol.name.clone());
+                    self.symbols.push(symbol);
+                    self.visit_children(node, source_code);
+                    self.scope.truncate(depth);
+                }
+                return;
+            }
+            "file_scoped_namespace_declaration" => {
+                // Applies to every following declaration in the file, so the
+                // symbol spans to the end of the compilation unit.
+                if let Some(mut symbol) = self.extract_named(node, source_code, SymbolKind::Namespace) {
+                    if let Some(unit) = node.parent() {
+                        symbol.end_line = unit.end_position().row + 1;
+                        symbol.full_range = node.start_byte()..unit.end_byte();
+                    }
+                    self.scope.push(symbol.name.clone());
+                    self.symbols.push(symbol);
+                }
+                return;
+            }
+            "delegate_declaration" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Type) {
+                    self.symbols.push(symbol);
+                }
+                return;
+            }
+            "method_declaration" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Method) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "destructor_declaration" => {
+                if let Some(symbol) = self.extract_destructor(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "constructor_declaration" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Constructor) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "property_declaration" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Property) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "event_declaration" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Event) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "event_field_declaration" => {
+                let symbols = self.extract_variables(node, source_code, SymbolKind::Event);
+                self.symbols.extend(symbols);
+                return;
+            }
+            "field_declaration" => {
+                let symbols = self.extract_variables(node, source_code, SymbolKind::Field);
+                self.symbols.extend(symbols);
+                return;
+            }
+            _ => {}
+        }
+
+        self.visit_children(node, source_code);
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
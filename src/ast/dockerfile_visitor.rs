@@ -0,0 +1,90 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct DockerfileVisitor {
+    symbols: Vec<Symbol>,
+}
+
+impl DockerfileVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+        }
+    }
+
+    /// `FROM image AS name` starts a named build stage, which lasts until
+    /// the next `FROM`. Unnamed stages are not reported.
+    fn extract_stage(&self, node: &Node, instructions: &[Node], source: &str) -> Option<Symbol> {
+        let alias = node.child_by_field_name("as")?;
+        let name = alias.utf8_text(source.as_bytes()).ok()?.to_string();
+        let last = instructions.last().unwrap_or(node);
+
+        Some(Symbol {
+            name_range: Some(alias.byte_range()),
+            end_line: last.end_position().row + 1,
+            full_range: node.start_byte()..last.end_byte(),
+            ..Symbol::new(SymbolKind::Module, name, node)
+        })
+    }
+
+    /// `ARG NAME=default` and `ENV NAME=value` (or the legacy `ENV NAME value`),
+    /// one variable per pair, inside the stage that declares them. Arguments
+    /// before the first `FROM` are global.
+    fn extract_variables(&self, node: &Node, source: &str, stage: Option<&str>) -> Vec<Symbol> {
+        let mut variables = Vec::new();
+        let mut cursor = node.walk();
+        for pair in node.named_children(&mut cursor).filter(|c| matches!(c.kind(), "arg_pair" | "env_pair")) {
+            let Some(name_node) = pair.child_by_field_name("name") else {
+                continue;
+            };
+            let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                continue;
+            };
+            let value = pair.child_by_field_name("default").or_else(|| pair.child_by_field_name("value"));
+
+            variables.push(Symbol {
+                name_range: Some(name_node.byte_range()),
+                body_range: value.map(|n| n.byte_range()),
+                container: stage.map(|s| s.to_string()),
+                qualified_name: Some(match stage {
+                    Some(stage) => format!("{}.{}", stage, name),
+                    None => name.to_string(),
+                }),
+                ..Symbol::new(SymbolKind::Variable, name.to_string(), &pair)
+            });
+        }
+        variables
+    }
+}
+
+impl LanguageVisitor for DockerfileVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        let mut cursor = node.walk();
+        let instructions: Vec<Node> = node.named_children(&mut cursor)
+            .filter(|n| n.kind() != "comment")
+            .collect();
+
+        let mut stage: Option<String> = None;
+        for (i, instruction) in instructions.iter().enumerate() {
+            match instruction.kind() {
+                "from_instruction" => {
+                    let rest = &instructions[i + 1..];
+                    let end = rest.iter().position(|n| n.kind() == "from_instruction").unwrap_or(rest.len());
+                    let symbol = self.extract_stage(instruction, &rest[..end], source_code);
+                    stage = symbol.as_ref().map(|s| s.name.clone());
+                    self.symbols.extend(symbol);
+                }
+                "arg_instruction" | "env_instruction" => {
+                    let variables = self.extract_variables(instruction, source_code, stage.as_deref());
+                    self.symbols.extend(variables);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
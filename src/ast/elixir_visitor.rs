@@ -0,0 +1,236 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct ElixirVisitor {
+    symbols: Vec<Symbol>,
+    /// Aliases of the enclosing `defmodule`s, e.g. `["MyApp.Accounts", "User"]`.
+    scope: Vec<String>,
+}
+
+impl ElixirVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+        }
+    }
+
+    fn container(&self) -> Option<String> {
+        visitor::scope_container(&self.scope, ".")
+    }
+
+    /// Name of a macro call such as `def`, `defmodule` or `spec`.
+    fn call_target<'a>(node: &Node, source: &'a str) -> Option<&'a str> {
+        if node.kind() != "call" {
+            return None;
+        }
+        let target = node.child_by_field_name("target")?;
+        if target.kind() != "identifier" {
+            return None;
+        }
+        target.utf8_text(source.as_bytes()).ok()
+    }
+
+    fn arguments<'tree>(node: &Node<'tree>) -> Option<Node<'tree>> {
+        let mut cursor = node.walk();
+        node.children(&mut cursor).find(|c| c.kind() == "arguments")
+    }
+
+    /// Name node and arity of a function head: `f(a, b)`, `f(a) when ...`
+    /// or a bare `f`. The same shape is used by `@spec f(a) :: b`.
+    fn function_head<'tree>(head: Node<'tree>, source: &str) -> Option<(Node<'tree>, usize)> {
+        let mut head = head;
+        while head.kind() == "binary_operator" {
+            head = head.child_by_field_name("left")?;
+        }
+        match head.kind() {
+            "identifier" => Some((head, 0)),
+            "call" => {
+                let name_node = head.child_by_field_name("target")?;
+                Self::call_target(&head, source)?;
+                let arity = Self::arguments(&head).map_or(0, |args| args.named_child_count());
+                Some((name_node, arity))
+            }
+            _ => None,
+        }
+    }
+
+    fn first_argument<'tree>(node: &Node<'tree>) -> Option<Node<'tree>> {
+        Self::arguments(node)?.named_child(0)
+    }
+
+    /// Functions are identified by name and arity, `MyApp.Accounts.get_user/1`.
+    fn function_qualified_name(&self, name: &str, arity: usize) -> String {
+        match self.container() {
+            Some(container) => format!("{}.{}/{}", container, name, arity),
+            None => format!("{}/{}", name, arity),
+        }
+    }
+
+    /// `@spec name(args) :: return`, as the qualified name of the function
+    /// it describes.
+    fn spec_signature(&self, node: &Node, source: &str) -> Option<String> {
+        if node.kind() != "unary_operator" {
+            return None;
+        }
+        let operand = node.child_by_field_name("operand")?;
+        if Self::call_target(&operand, source)? != "spec" {
+            return None;
+        }
+        let (name_node, arity) = Self::function_head(Self::first_argument(&operand)?, source)?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?;
+        Some(self.function_qualified_name(name, arity))
+    }
+
+    /// `def`/`defp`/`defdelegate` define functions and `defmacro`/`defguard`
+    /// (and their private forms) macros.
+    fn extract_function(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let kind = match Self::call_target(node, source)? {
+            "def" | "defp" | "defdelegate" => SymbolKind::Function,
+            "defmacro" | "defmacrop" | "defguard" | "defguardp" => SymbolKind::Macro,
+            _ => return None,
+        };
+        let (name_node, arity) = Self::function_head(Self::first_argument(node)?, source)?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+        let qualified_name = self.function_qualified_name(&name, arity);
+
+        let mut cursor = node.walk();
+        let body = node.children(&mut cursor).find(|c| c.kind() == "do_block");
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: body.map(|n| n.byte_range()),
+            container: self.container(),
+            qualified_name: Some(qualified_name),
+            ..Symbol::new(kind, name, node)
+        })
+    }
+
+    /// Walk the expressions of a module body (or a script), attaching a
+    /// preceding `@spec` to its function and folding consecutive clauses
+    /// of the same function into a single symbol.
+    fn visit_block(&mut self, block: &Node, source: &str) {
+        let mut spec: Option<(String, Node)> = None;
+        let mut previous: Option<usize> = None;
+        let mut cursor = block.walk();
+
+        for node in block.named_children(&mut cursor) {
+            if let Some(signature) = self.spec_signature(&node, source) {
+                spec = Some((signature, node));
+                previous = None;
+                continue;
+            }
+
+            let Some(mut symbol) = self.extract_function(&node, source) else {
+                // Other module attributes (`@doc`, `@impl`) may sit between clauses
+                if node.kind() != "unary_operator" {
+                    previous = None;
+                }
+                self.visit(&node, source);
+                continue;
+            };
+
+            if let Some(index) = previous
+                && self.symbols[index].qualified_name == symbol.qualified_name
+            {
+                let clause = &mut self.symbols[index];
+                clause.end_line = symbol.end_line;
+                clause.full_range.end = symbol.full_range.end;
+                continue;
+            }
+
+            if let Some((signature, spec_node)) = spec.take()
+                && symbol.qualified_name.as_deref() == Some(signature.as_str())
+            {
+                symbol.start_line = spec_node.start_position().row + 1;
+                symbol.full_range.start = spec_node.start_byte();
+            }
+
+            self.symbols.push(symbol);
+            previous = Some(self.symbols.len() - 1);
+        }
+    }
+
+    fn visit_module(&mut self, node: &Node, source: &str) {
+        let Some(alias) = Self::first_argument(node) else {
+            return;
+        };
+        let Ok(alias_text) = alias.utf8_text(source.as_bytes()) else {
+            return;
+        };
+
+        // `defmodule MyApp.Accounts` is `Accounts` inside `MyApp`
+        let (prefix, name) = match alias_text.rsplit_once('.') {
+            Some((prefix, name)) => (Some(prefix), name),
+            None => (None, alias_text),
+        };
+        let container = match (self.container(), prefix) {
+            (Some(outer), Some(prefix)) => Some(format!("{}.{}", outer, prefix)),
+            (outer, prefix) => outer.or(prefix.map(|p| p.to_string())),
+        };
+
+        let mut cursor = node.walk();
+        let body = node.children(&mut cursor).find(|c| c.kind() == "do_block");
+
+        self.symbols.push(Symbol {
+            name_range: Some(alias.byte_range()),
+            body_range: body.map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(SymbolKind::Module, name.to_string(), container, ".", node)
+        });
+
+        if let Some(body) = body {
+            self.scope.push(alias_text.to_string());
+            self.visit_block(&body, source);
+            self.scope.pop();
+        }
+    }
+
+    /// `defstruct` defines the struct of the enclosing module, so it shares
+    /// the module's name and qualified name.
+    fn extract_struct(&self, node: &Node) -> Option<Symbol> {
+        let module = self.symbols.iter().rev().find(|s| {
+            s.kind == SymbolKind::Module && s.full_range.start <= node.start_byte() && node.end_byte() <= s.full_range.end
+        })?;
+
+        Some(Symbol {
+            container: module.container.clone(),
+            qualified_name: module.qualified_name.clone(),
+            ..Symbol::new(SymbolKind::Struct, module.name.clone(), node)
+        })
+    }
+}
+
+impl LanguageVisitor for ElixirVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "source" => self.visit_block(node, source_code),
+            "call" => match Self::call_target(node, source_code) {
+                Some("defmodule") => self.visit_module(node, source_code),
+                Some("defstruct") => {
+                    if let Some(symbol) = self.extract_struct(node) {
+                        self.symbols.push(symbol);
+                    }
+                }
+                _ => {
+                    for i in 0..node.child_count() {
+                        if let Some(child) = node.child(i) {
+                            self.visit(&child, source_code);
+                        }
+                    }
+                }
+            },
+            _ => {
+                for i in 0..node.child_count() {
+                    if let Some(child) = node.child(i) {
+                        self.visit(&child, source_code);
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
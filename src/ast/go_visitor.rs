@@ -1,4 +1,4 @@
-use super::symbol::{Symbol, SymbolKind};
+use super::symbol::{Symbol, SymbolKind, Visibility};
 use super::visitor::LanguageVisitor;
 use tree_sitter::Node;
 
@@ -18,63 +18,153 @@ impl GoVisitor {
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
         
         Some(Symbol {
-            kind: SymbolKind::Function,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
             body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..Symbol::new(SymbolKind::Function, name, node)
         })
     }
 
-    fn extract_struct(&self, node: &Node, source: &str) -> Option<Symbol> {
+    /// `type Name ...`: structs and interfaces by their underlying type,
+    /// anything else (`type ID string`, `type Handler func()`, aliases such
+    /// as `type Any = interface{}`) as a named type.
+    fn extract_type(&self, node: &Node, source: &str) -> Option<Symbol> {
         let name_node = node.child_by_field_name("name")?;
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
-        
+        let type_node = node.child_by_field_name("type")?;
+        let kind = match (node.kind(), type_node.kind()) {
+            ("type_spec", "struct_type") => SymbolKind::Struct,
+            ("type_spec", "interface_type") => SymbolKind::Interface,
+            _ => SymbolKind::Type,
+        };
+
         Some(Symbol {
-            kind: SymbolKind::Struct,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
-            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            body_range: Some(type_node.byte_range()),
+            ..Symbol::new(kind, name, node)
         })
     }
 
+    /// Fields of a struct type, `ID, Ref int` declaring one per name.
+    /// Embedded fields (`*Base`, `io.Reader`) are named after their type.
+    fn extract_fields(&self, struct_type: &Node, source: &str, container: &str) -> Vec<Symbol> {
+        let mut fields = Vec::new();
+        let mut cursor = struct_type.walk();
+        let Some(list) = struct_type.named_children(&mut cursor).find(|c| c.kind() == "field_declaration_list") else {
+            return fields;
+        };
+
+        let mut cursor = list.walk();
+        for declaration in list.named_children(&mut cursor).filter(|c| c.kind() == "field_declaration") {
+            let mut names: Vec<Node> = {
+                let mut cursor = declaration.walk();
+                declaration.children_by_field_name("name", &mut cursor).collect()
+            };
+            if names.is_empty() {
+                let embedded = declaration.child_by_field_name("type")
+                    .map(|t| t.child_by_field_name("name").unwrap_or(t));
+                names.extend(embedded);
+            }
+
+            for name_node in names {
+                let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                    continue;
+                };
+                fields.push(Symbol {
+                    name_range: Some(name_node.byte_range()),
+                    container: Some(container.to_string()),
+                    qualified_name: Some(format!("{}.{}", container, name)),
+                    ..Symbol::new(SymbolKind::Field, name.to_string(), &declaration)
+                });
+            }
+        }
+        fields
+    }
+
+    /// Constants of a `const` declaration. Those typed with a named type are
+    /// that type's enum members, `Status.Paid`; a spec without a value
+    /// repeats the type (and expression) of the one before it, as `iota`
+    /// groups do.
+    fn extract_constants(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut constants = Vec::new();
+        let mut group_type: Option<String> = None;
+        let mut cursor = node.walk();
+        for spec in node.named_children(&mut cursor).filter(|c| c.kind() == "const_spec") {
+            if spec.child_by_field_name("value").is_some() {
+                group_type = spec.child_by_field_name("type")
+                    .filter(|t| t.kind() == "type_identifier")
+                    .and_then(|t| t.utf8_text(source.as_bytes()).ok())
+                    .filter(|t| !is_predeclared_type(t))
+                    .map(|t| t.to_string());
+            }
+
+            let mut cursor = spec.walk();
+            // The commas between names are tagged as names too
+            for name_node in spec.children_by_field_name("name", &mut cursor).filter(|n| n.kind() == "identifier") {
+                let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                    continue;
+                };
+                if name == "_" {
+                    continue;
+                }
+                constants.push(Symbol {
+                    name_range: Some(name_node.byte_range()),
+                    container: group_type.clone(),
+                    qualified_name: group_type.as_ref().map(|t| format!("{}.{}", t, name)),
+                    ..Symbol::new(
+                        if group_type.is_some() { SymbolKind::EnumMember } else { SymbolKind::Constant },
+                        name.to_string(),
+                        &spec,
+                    )
+                });
+            }
+        }
+        constants
+    }
+
     fn extract_variable(&self, node: &Node, source: &str) -> Option<Symbol> {
         // For var_spec
         let name_node = node.child_by_field_name("name")?;
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
         
         Some(Symbol {
-            kind: SymbolKind::Variable,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
-            body_range: None,
+            ..Symbol::new(SymbolKind::Variable, name, node)
         })
     }
 }
 
+/// Types built into the language, which constants of their own are not
+/// members of.
+fn is_predeclared_type(name: &str) -> bool {
+    matches!(
+        name,
+        "bool" | "byte" | "rune" | "string" | "error" | "any"
+            | "int" | "int8" | "int16" | "int32" | "int64"
+            | "uint" | "uint8" | "uint16" | "uint32" | "uint64" | "uintptr"
+            | "float32" | "float64" | "complex64" | "complex128"
+    )
+}
+
 impl LanguageVisitor for GoVisitor {
     fn visit(&mut self, node: &Node, source_code: &str) {
         let maybe_symbol = match node.kind() {
             "function_declaration" | "method_declaration" => self.extract_function(node, source_code),
-            "type_spec" => {
-                // Check if it's a struct type
-                if let Some(type_node) = node.child_by_field_name("type") {
-                    if type_node.kind() == "struct_type" {
-                        self.extract_struct(node, source_code)
-                    } else {
-                        None
-                    }
-                } else {
-                    None
+            "type_spec" | "type_alias" => {
+                // Struct fields follow the struct they belong to
+                if let Some(symbol) = self.extract_type(node, source_code) {
+                    let fields = match (symbol.kind, node.child_by_field_name("type")) {
+                        (SymbolKind::Struct, Some(struct_type)) => self.extract_fields(&struct_type, source_code, &symbol.name),
+                        _ => Vec::new(),
+                    };
+                    self.symbols.push(symbol);
+                    self.symbols.extend(fields);
                 }
+                None
+            }
+            "const_declaration" => {
+                let constants = self.extract_constants(node, source_code);
+                self.symbols.extend(constants);
+                None
             }
             "var_spec" => self.extract_variable(node, source_code),
             _ => None,
@@ -92,7 +182,13 @@ impl LanguageVisitor for GoVisitor {
         }
     }
 
-    fn get_symbols(self) -> Vec<Symbol> {
+    /// Names starting with an upper-case letter are exported from their
+    /// package.
+    fn get_symbols(mut self) -> Vec<Symbol> {
+        for symbol in &mut self.symbols {
+            let exported = symbol.name.starts_with(|c: char| c.is_uppercase());
+            symbol.visibility = Some(if exported { Visibility::Exported } else { Visibility::Private });
+        }
         self.symbols
     }
 }
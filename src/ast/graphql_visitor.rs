@@ -0,0 +1,137 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct GraphqlVisitor {
+    symbols: Vec<Symbol>,
+    /// Names of the root operation types whose fields are the API's
+    /// queries, mutations and subscriptions.
+    root_types: Vec<String>,
+}
+
+impl GraphqlVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            root_types: vec!["Query".to_string(), "Mutation".to_string(), "Subscription".to_string()],
+        }
+    }
+
+    fn name_node<'tree>(node: &Node<'tree>) -> Option<Node<'tree>> {
+        let mut cursor = node.walk();
+        node.children(&mut cursor).find(|c| c.kind() == "name")
+    }
+
+    fn extract_type(&self, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let name_node = Self::name_node(node)?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            qualified_name: Some(name.clone()),
+            ..Symbol::new(kind, name, node)
+        })
+    }
+
+    /// Fields of a root operation type (`type Query { order(id: ID!): Order }`)
+    /// are the operations clients can call.
+    fn extract_operations(&self, node: &Node, source: &str, type_name: &str) -> Vec<Symbol> {
+        let mut cursor = node.walk();
+        let Some(fields) = node.children(&mut cursor).find(|c| c.kind() == "fields_definition") else {
+            return Vec::new();
+        };
+
+        let mut cursor = fields.walk();
+        fields.named_children(&mut cursor)
+            .filter(|field| field.kind() == "field_definition")
+            .filter_map(|field| {
+                let name_node = Self::name_node(&field)?;
+                let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+                Some(Symbol {
+                    name_range: Some(name_node.byte_range()),
+                    container: Some(type_name.to_string()),
+                    qualified_name: Some(format!("{}.{}", type_name, name)),
+                    ..Symbol::new(SymbolKind::Method, name, &field)
+                })
+            })
+            .collect()
+    }
+
+    /// `schema { query: RootQuery }` renames the root operation types.
+    fn collect_root_types(&mut self, node: &Node, source: &str) {
+        let mut cursor = node.walk();
+        for definition in node.named_children(&mut cursor) {
+            if definition.kind() != "root_operation_type_definition" {
+                continue;
+            }
+            let mut cursor = definition.walk();
+            let named_type = definition.named_children(&mut cursor).find(|c| c.kind() == "named_type");
+            if let Some(name) = named_type.and_then(|n| n.utf8_text(source.as_bytes()).ok()) {
+                self.root_types.push(name.to_string());
+            }
+        }
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for GraphqlVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        let kind = match node.kind() {
+            "document" => {
+                // The schema definition may follow the types it names
+                let mut cursor = node.walk();
+                let schemas: Vec<Node> = node.named_children(&mut cursor)
+                    .filter_map(|definition| definition.named_child(0)?.named_child(0))
+                    .filter(|definition| definition.kind() == "schema_definition")
+                    .collect();
+                for schema in schemas {
+                    self.collect_root_types(&schema, source_code);
+                }
+                self.visit_children(node, source_code);
+                return;
+            }
+            "object_type_definition" => SymbolKind::Class,
+            "interface_type_definition" => SymbolKind::Interface,
+            "input_object_type_definition" => SymbolKind::Struct,
+            "enum_type_definition" => SymbolKind::Enum,
+            "union_type_definition" => SymbolKind::Union,
+            "scalar_type_definition" => SymbolKind::Type,
+            "operation_definition" => SymbolKind::Function,
+            "object_type_extension" => {
+                // `extend type Mutation { ... }` only adds operations
+                if let Some(name) = Self::name_node(node).and_then(|n| n.utf8_text(source_code.as_bytes()).ok())
+                    && self.root_types.iter().any(|root| root == name)
+                {
+                    let operations = self.extract_operations(node, source_code, name);
+                    self.symbols.extend(operations);
+                }
+                return;
+            }
+            _ => {
+                self.visit_children(node, source_code);
+                return;
+            }
+        };
+
+        if let Some(symbol) = self.extract_type(node, source_code, kind) {
+            let is_root = kind == SymbolKind::Class && self.root_types.contains(&symbol.name);
+            let name = symbol.name.clone();
+            self.symbols.push(symbol);
+            if is_root {
+                let operations = self.extract_operations(node, source_code, &name);
+                self.symbols.extend(operations);
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
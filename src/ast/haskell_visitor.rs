@@ -0,0 +1,164 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct HaskellVisitor {
+    symbols: Vec<Symbol>,
+    /// Module name from the header, e.g. `Data.Shapes`.
+    module: Option<String>,
+}
+
+impl HaskellVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            module: None,
+        }
+    }
+
+    fn extract_named(&self, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..visitor::scoped_symbol(kind, name, self.module.clone(), ".", node)
+        })
+    }
+
+    /// `instance Describable Shape` is named after the class and the types.
+    fn extract_instance(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let class = name_node.utf8_text(source.as_bytes()).ok()?;
+        let name = match node.child_by_field_name("patterns") {
+            Some(patterns) => format!("{} {}", class, patterns.utf8_text(source.as_bytes()).ok()?),
+            None => class.to_string(),
+        };
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..visitor::scoped_symbol(SymbolKind::Impl, name, self.module.clone(), ".", node)
+        })
+    }
+
+    /// Walk a declaration list, folding a type signature and the equations
+    /// that follow it (`area (Circle r) = ...`, `area (Square s) = ...`)
+    /// into a single symbol spanning all of them. Inside a class or instance
+    /// (`members`) every binding is a method.
+    fn visit_declarations(&mut self, list: &Node, source: &str, container: Option<String>, members: bool) {
+        let mut previous: Option<usize> = None;
+        let mut cursor = list.walk();
+
+        for node in list.named_children(&mut cursor) {
+            let kind = match node.kind() {
+                "signature" | "function" | "bind" if members => SymbolKind::Method,
+                "signature" => {
+                    let is_function = node.child_by_field_name("type").is_some_and(|t| t.kind() == "function");
+                    if is_function { SymbolKind::Function } else { SymbolKind::Variable }
+                }
+                "function" => SymbolKind::Function,
+                "bind" => SymbolKind::Variable,
+                _ => {
+                    previous = None;
+                    self.visit(&node, source);
+                    continue;
+                }
+            };
+
+            let Some(name_node) = node.child_by_field_name("name") else {
+                previous = None;
+                continue;
+            };
+            let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                continue;
+            };
+
+            if let Some(index) = previous
+                && self.symbols[index].name == name
+            {
+                let symbol = &mut self.symbols[index];
+                symbol.end_line = node.end_position().row + 1;
+                symbol.full_range.end = node.end_byte();
+                if symbol.kind == SymbolKind::Variable && kind == SymbolKind::Function {
+                    symbol.kind = kind;
+                }
+                continue;
+            }
+
+            self.symbols.push(Symbol {
+                name_range: Some(name_node.byte_range()),
+                ..visitor::scoped_symbol(kind, name.to_string(), container.clone(), ".", &node)
+            });
+            previous = Some(self.symbols.len() - 1);
+        }
+    }
+}
+
+impl LanguageVisitor for HaskellVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "header" => {
+                if let Some(module) = node.child_by_field_name("module")
+                    && let Ok(name) = module.utf8_text(source_code.as_bytes())
+                {
+                    // The module spans the whole file
+                    let root = node.parent().unwrap_or(*node);
+                    self.symbols.push(Symbol {
+                        name_range: Some(module.byte_range()),
+                        qualified_name: Some(name.to_string()),
+                        ..Symbol::new(SymbolKind::Module, name.to_string(), &root)
+                    });
+                    self.module = Some(name.to_string());
+                }
+            }
+            "import" => {
+                if let Some(module) = node.child_by_field_name("module")
+                    && let Ok(name) = module.utf8_text(source_code.as_bytes())
+                {
+                    self.symbols.push(Symbol {
+                        name_range: Some(module.byte_range()),
+                        ..Symbol::new(SymbolKind::Import, name.to_string(), node)
+                    });
+                }
+            }
+            "declarations" => {
+                let module = self.module.clone();
+                self.visit_declarations(node, source_code, module, false);
+            }
+            "data_type" | "newtype" | "type_synonym" | "type_family" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Type) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "class" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Trait) {
+                    let container = symbol.qualified_name.clone();
+                    self.symbols.push(symbol);
+                    if let Some(declarations) = node.child_by_field_name("declarations") {
+                        self.visit_declarations(&declarations, source_code, container, true);
+                    }
+                }
+            }
+            "instance" => {
+                if let Some(symbol) = self.extract_instance(node, source_code) {
+                    let container = symbol.qualified_name.clone();
+                    self.symbols.push(symbol);
+                    if let Some(declarations) = node.child_by_field_name("declarations") {
+                        self.visit_declarations(&declarations, source_code, container, true);
+                    }
+                }
+            }
+            _ => {
+                for i in 0..node.child_count() {
+                    if let Some(child) = node.child(i) {
+                        self.visit(&child, source_code);
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
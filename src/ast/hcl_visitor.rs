@@ -0,0 +1,123 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct HclVisitor {
+    symbols: Vec<Symbol>,
+}
+
+impl HclVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+        }
+    }
+
+    /// Block labels are usually strings (`resource "aws_instance" "web"`)
+    /// but may also be bare identifiers.
+    fn labels<'tree>(node: &Node<'tree>, source: &str) -> Vec<(String, Node<'tree>)> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .skip(1)
+            .filter(|c| matches!(c.kind(), "string_lit" | "identifier"))
+            .filter_map(|label| {
+                let mut cursor = label.walk();
+                let literal = label.named_children(&mut cursor).find(|c| c.kind() == "template_literal");
+                let label = literal.unwrap_or(label);
+                let text = label.utf8_text(source.as_bytes()).ok()?;
+                Some((text.trim_matches('"').to_string(), label))
+            })
+            .collect()
+    }
+
+    fn body<'tree>(node: &Node<'tree>) -> Option<Node<'tree>> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor).find(|c| c.kind() == "body")
+    }
+
+    /// Symbols are qualified by the address Terraform uses to reference
+    /// them: `aws_instance.web`, `data.aws_ami.ubuntu`, `module.vpc` or
+    /// `var.region`.
+    fn addressed_symbol(kind: SymbolKind, address: Vec<&str>, name_node: &Node, node: &Node) -> Symbol {
+        let (name, container) = address.split_last().expect("address has a name");
+        let container = if container.is_empty() {
+            None
+        } else {
+            Some(container.join("."))
+        };
+
+        Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: Self::body(node).map(|n| n.byte_range()),
+            container,
+            qualified_name: Some(address.join(".")),
+            ..Symbol::new(kind, name.to_string(), node)
+        }
+    }
+
+    fn extract_block(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let block_type = node.named_child(0)?.utf8_text(source.as_bytes()).ok()?;
+        let labels = Self::labels(node, source);
+
+        let (kind, prefix, count) = match block_type {
+            "resource" => (SymbolKind::Class, None, 2),
+            "data" => (SymbolKind::Class, Some("data"), 2),
+            "module" => (SymbolKind::Module, Some("module"), 1),
+            "variable" => (SymbolKind::Variable, Some("var"), 1),
+            "output" => (SymbolKind::Property, Some("output"), 1),
+            _ => return None,
+        };
+        if labels.len() < count {
+            return None;
+        }
+
+        let address: Vec<&str> = prefix.into_iter()
+            .chain(labels[..count].iter().map(|(label, _)| label.as_str()))
+            .collect();
+        let (_, name_node) = &labels[count - 1];
+        Some(Self::addressed_symbol(kind, address, name_node, node))
+    }
+
+    /// Each attribute of a `locals` block is a variable, `local.name`.
+    fn extract_locals(&mut self, node: &Node, source: &str) {
+        let Some(body) = Self::body(node) else {
+            return;
+        };
+        let mut cursor = body.walk();
+        for attribute in body.named_children(&mut cursor).filter(|c| c.kind() == "attribute") {
+            let Some(name_node) = attribute.named_child(0) else {
+                continue;
+            };
+            let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                continue;
+            };
+            self.symbols.push(Self::addressed_symbol(SymbolKind::Variable, vec!["local", name], &name_node, &attribute));
+        }
+    }
+}
+
+impl LanguageVisitor for HclVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "block" => {
+                let block_type = node.named_child(0).and_then(|n| n.utf8_text(source_code.as_bytes()).ok());
+                if block_type == Some("locals") {
+                    self.extract_locals(node, source_code);
+                } else if let Some(symbol) = self.extract_block(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            _ => {
+                for i in 0..node.child_count() {
+                    if let Some(child) = node.child(i) {
+                        self.visit(&child, source_code);
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,115 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct HtmlVisitor {
+    symbols: Vec<Symbol>,
+}
+
+impl HtmlVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+        }
+    }
+
+    /// The opening tag of an element, `<div ...>` or `<br ... />`.
+    pub fn start_tag<'tree>(node: &Node<'tree>) -> Option<Node<'tree>> {
+        node.child(0).filter(|c| matches!(c.kind(), "start_tag" | "self_closing_tag"))
+    }
+
+    pub fn tag_name<'a>(node: &Node, source: &'a str) -> Option<&'a str> {
+        let start_tag = Self::start_tag(node)?;
+        let mut cursor = start_tag.walk();
+        let name = start_tag.children(&mut cursor).find(|c| c.kind() == "tag_name")?;
+        name.utf8_text(source.as_bytes()).ok()
+    }
+
+    /// The value node of attribute `name`, without its quotes.
+    pub fn attribute<'tree>(node: &Node<'tree>, source: &str, name: &str) -> Option<Node<'tree>> {
+        let start_tag = Self::start_tag(node)?;
+        let mut cursor = start_tag.walk();
+        let attribute = start_tag.children(&mut cursor).find(|attribute| {
+            attribute.kind() == "attribute"
+                && attribute.named_child(0).and_then(|n| n.utf8_text(source.as_bytes()).ok()) == Some(name)
+        })?;
+        let value = attribute.named_child(1)?;
+        match value.kind() {
+            "quoted_attribute_value" => value.named_child(0),
+            _ => Some(value),
+        }
+    }
+
+    /// Elements with an `id` are named by it and qualified as `tag#id`.
+    fn extract_element(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let id = Self::attribute(node, source, "id")?;
+        let name = id.utf8_text(source.as_bytes()).ok()?.to_string();
+        let tag = Self::tag_name(node, source)?;
+
+        // Void elements (`<img>`) have no end tag and would otherwise take
+        // in the whitespace that follows them
+        let mut cursor = node.walk();
+        let has_end_tag = node.children(&mut cursor).any(|c| c.kind() == "end_tag");
+        let span = if has_end_tag { *node } else { Self::start_tag(node)? };
+
+        Some(Symbol {
+            name_range: Some(id.byte_range()),
+            qualified_name: Some(format!("{}#{}", tag, name)),
+            ..Symbol::new(SymbolKind::Element, name, &span)
+        })
+    }
+
+    /// Inline `<script>` and `<style>` content, as a code block named after
+    /// its language so that its symbols can be extracted in turn. Scripts
+    /// of other types (`application/json`, templates) are left alone.
+    fn extract_raw_block(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let language = match node.kind() {
+            "script_element" => {
+                let script_type = Self::attribute(node, source, "type")
+                    .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+                    .unwrap_or("text/javascript");
+                if script_type != "module" && !script_type.contains("javascript") {
+                    return None;
+                }
+                "js"
+            }
+            _ => "css",
+        };
+        let mut cursor = node.walk();
+        let content = node.children(&mut cursor).find(|c| c.kind() == "raw_text")?;
+
+        Some(Symbol {
+            body_range: Some(content.byte_range()),
+            ..Symbol::new(SymbolKind::CodeBlock, language.to_string(), node)
+        })
+    }
+}
+
+impl LanguageVisitor for HtmlVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "element" | "script_element" | "style_element" => {
+                if let Some(symbol) = self.extract_element(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+                if node.kind() != "element" {
+                    if let Some(symbol) = self.extract_raw_block(node, source_code) {
+                        self.symbols.push(symbol);
+                    }
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,132 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct JavaVisitor {
+    symbols: Vec<Symbol>,
+    /// Package followed by the names of the enclosing types.
+    scope: Vec<String>,
+}
+
+impl JavaVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+        }
+    }
+
+    fn container(&self) -> Option<String> {
+        visitor::scope_container(&self.scope, ".")
+    }
+
+    /// Annotations among the declaration's modifiers, without their `@`.
+    fn annotations(node: &Node, source: &str) -> Vec<String> {
+        let mut cursor = node.walk();
+        let Some(modifiers) = node.children(&mut cursor).find(|c| c.kind() == "modifiers") else {
+            return Vec::new();
+        };
+        let mut cursor = modifiers.walk();
+        modifiers.children(&mut cursor)
+            .filter(|c| matches!(c.kind(), "marker_annotation" | "annotation"))
+            .filter_map(|annotation| annotation.utf8_text(source.as_bytes()).ok())
+            .map(|text| text.trim_start_matches('@').to_string())
+            .collect()
+    }
+
+    fn scoped_symbol(&self, kind: SymbolKind, name_node: &Node, node: &Node, source: &str) -> Option<Symbol> {
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            decorators: Self::annotations(node, source),
+            ..visitor::scoped_symbol(kind, name, self.container(), ".", node)
+        })
+    }
+
+    fn extract_named(&self, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        self.scoped_symbol(kind, &name_node, node, source)
+    }
+
+    /// Field and interface constant declarations may declare several names
+    /// (`int x, y;`); each becomes its own symbol spanning the declaration.
+    fn extract_fields(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut cursor = node.walk();
+        node.children_by_field_name("declarator", &mut cursor)
+            .filter_map(|declarator| {
+                let name_node = declarator.child_by_field_name("name")?;
+                self.scoped_symbol(SymbolKind::Field, &name_node, node, source)
+            })
+            .collect()
+    }
+
+    fn type_kind(node_kind: &str) -> Option<SymbolKind> {
+        match node_kind {
+            "class_declaration" => Some(SymbolKind::Class),
+            "interface_declaration" | "annotation_type_declaration" => Some(SymbolKind::Interface),
+            "enum_declaration" => Some(SymbolKind::Enum),
+            "record_declaration" => Some(SymbolKind::Record),
+            _ => None,
+        }
+    }
+}
+
+impl LanguageVisitor for JavaVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        if let Some(kind) = Self::type_kind(node.kind()) {
+            if let Some(symbol) = self.extract_named(node, source_code, kind) {
+                self.scope.push(symbol.name.clone());
+                self.symbols.push(symbol);
+
+                for i in 0..node.child_count() {
+                    if let Some(child) = node.child(i) {
+                        self.visit(&child, source_code);
+                    }
+                }
+
+                self.scope.pop();
+            }
+            return;
+        }
+
+        match node.kind() {
+            "package_declaration" => {
+                if let Some(package) = node.named_child(0)
+                    && let Ok(package) = package.utf8_text(source_code.as_bytes())
+                {
+                    self.scope = vec![package.to_string()];
+                }
+                return;
+            }
+            "method_declaration" | "annotation_type_element_declaration" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Method) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "constructor_declaration" | "compact_constructor_declaration" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Constructor) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "field_declaration" | "constant_declaration" => {
+                let symbols = self.extract_fields(node, source_code);
+                self.symbols.extend(symbols);
+                return;
+            }
+            _ => {}
+        }
+
+        // Recurse into children
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -1,15 +1,45 @@
-use super::symbol::{Symbol, SymbolKind};
+use super::symbol::{Symbol, SymbolKind, Visibility};
 use super::visitor::LanguageVisitor;
+use std::collections::HashSet;
 use tree_sitter::Node;
 
 pub struct JsVisitor {
     symbols: Vec<Symbol>,
+    /// Names exported by a separate `export { a, b as c }`.
+    exported: HashSet<String>,
 }
 
 impl JsVisitor {
     pub fn new() -> Self {
         Self {
             symbols: Vec::new(),
+            exported: HashSet::new(),
+        }
+    }
+
+    /// Declarations are exported when written as `export function`,
+    /// `export class` or `export const`, and private to the module otherwise.
+    fn visibility(node: &Node) -> Visibility {
+        let declaration = match node.kind() {
+            "variable_declarator" => node.parent(),
+            _ => Some(*node),
+        };
+        match declaration.and_then(|d| d.parent()) {
+            Some(parent) if parent.kind() == "export_statement" => Visibility::Exported,
+            _ => Visibility::Private,
+        }
+    }
+
+    fn collect_exports(&mut self, node: &Node, source: &str) {
+        let mut cursor = node.walk();
+        let Some(clause) = node.named_children(&mut cursor).find(|c| c.kind() == "export_clause") else {
+            return;
+        };
+        let mut cursor = clause.walk();
+        for specifier in clause.named_children(&mut cursor) {
+            if let Some(name) = specifier.child_by_field_name("name").and_then(|n| n.utf8_text(source.as_bytes()).ok()) {
+                self.exported.insert(name.to_string());
+            }
         }
     }
 
@@ -18,13 +48,9 @@ impl JsVisitor {
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
         
         Some(Symbol {
-            kind: SymbolKind::Function,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
             body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..Symbol::new(SymbolKind::Function, name, node)
         })
     }
 
@@ -33,29 +59,125 @@ impl JsVisitor {
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
         
         Some(Symbol {
-            kind: SymbolKind::Class,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
             body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            decorators: Self::decorators(node, source),
+            ..Symbol::new(SymbolKind::Class, name, node)
         })
     }
 
+    /// Decorators of a class or class member, without their `@`. Those of
+    /// an exported class may precede the `export` keyword instead, and the
+    /// TypeScript grammar places a method's before it in the class body.
+    fn decorators(node: &Node, source: &str) -> Vec<String> {
+        let mut decorators = Self::preceding_decorators(node);
+        let export = node.parent().filter(|p| p.kind() == "export_statement");
+        for node in export.iter().chain(std::iter::once(node)) {
+            let mut cursor = node.walk();
+            decorators.extend(node.children_by_field_name("decorator", &mut cursor));
+        }
+        decorators.into_iter()
+            .filter_map(|decorator| decorator.named_child(0)?.utf8_text(source.as_bytes()).ok())
+            .map(|text| text.to_string())
+            .collect()
+    }
+
+    /// Decorators written before a TypeScript method, first one first.
+    fn preceding_decorators<'a>(node: &Node<'a>) -> Vec<Node<'a>> {
+        let mut decorators = Vec::new();
+        if node.kind() == "method_definition" {
+            let mut sibling = node.prev_named_sibling();
+            while let Some(decorator) = sibling.filter(|s| s.kind() == "decorator") {
+                decorators.push(decorator);
+                sibling = decorator.prev_named_sibling();
+            }
+            decorators.reverse();
+        }
+        decorators
+    }
+
+    /// Members are public unless named `#private`, or in TypeScript
+    /// declared `private` or `protected`.
+    fn member_visibility(member: &Node, name: &str, source: &str) -> Visibility {
+        let mut cursor = member.walk();
+        let modifier = member.children(&mut cursor)
+            .find(|c| c.kind() == "accessibility_modifier")
+            .and_then(|c| c.utf8_text(source.as_bytes()).ok());
+        match modifier {
+            Some("private") => Visibility::Private,
+            Some("protected") => Visibility::Protected,
+            _ if name.starts_with('#') => Visibility::Private,
+            _ => Visibility::Public,
+        }
+    }
+
+    /// Class fields (`items = []`, `#secret`, TypeScript's `name: string`)
+    /// and methods.
+    fn extract_members(&self, node: &Node, source: &str, container: &str) -> Vec<Symbol> {
+        let Some(body) = node.child_by_field_name("body") else {
+            return Vec::new();
+        };
+        let mut cursor = body.walk();
+        body.named_children(&mut cursor)
+            .filter_map(|member| {
+                let (kind, name_node, body) = match member.kind() {
+                    "field_definition" => (SymbolKind::Field, member.child_by_field_name("property")?, member.child_by_field_name("value")),
+                    "public_field_definition" => (SymbolKind::Field, member.child_by_field_name("name")?, member.child_by_field_name("value")),
+                    "method_definition" => (SymbolKind::Method, member.child_by_field_name("name")?, member.child_by_field_name("body")),
+                    _ => return None,
+                };
+                let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+                let kind = if kind == SymbolKind::Method && name == "constructor" { SymbolKind::Constructor } else { kind };
+                let visibility = Self::member_visibility(&member, &name, source);
+                let mut symbol = Symbol {
+                    name_range: Some(name_node.byte_range()),
+                    body_range: body.map(|n| n.byte_range()),
+                    container: Some(container.to_string()),
+                    qualified_name: Some(format!("{}.{}", container, name)),
+                    decorators: Self::decorators(&member, source),
+                    visibility: Some(visibility),
+                    ..Symbol::new(kind, name, &member)
+                };
+                // A method starts at its first decorator, as in JavaScript
+                if let Some(first) = Self::preceding_decorators(&member).first() {
+                    symbol.start_line = first.start_position().row + 1;
+                    symbol.full_range.start = first.start_byte();
+                }
+                Some(symbol)
+            })
+            .collect()
+    }
+
     fn extract_variable(&self, node: &Node, source: &str) -> Option<Symbol> {
         // For variable_declarator
         let name_node = node.child_by_field_name("name")?;
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
         
         Some(Symbol {
-            kind: SymbolKind::Variable,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
             body_range: node.child_by_field_name("value").map(|n| n.byte_range()),
+            ..Symbol::new(SymbolKind::Variable, name, node)
+        })
+    }
+
+    /// `customElements.define('checkout-button', CheckoutButton)` defines an
+    /// element named after its tag, implemented by the class.
+    fn extract_custom_element(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let function = node.child_by_field_name("function")?.utf8_text(source.as_bytes()).ok()?;
+        if !function.ends_with("customElements.define") {
+            return None;
+        }
+        let arguments = node.child_by_field_name("arguments")?;
+        let tag = arguments.named_child(0).filter(|n| n.kind() == "string")?;
+        let name = tag.utf8_text(source.as_bytes()).ok()?.trim_matches(|c| c == '"' || c == '\'').to_string();
+        let class = arguments.named_child(1)
+            .filter(|n| n.kind() == "identifier")
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok());
+
+        Some(Symbol {
+            name_range: Some(tag.byte_range()),
+            implementation: class.map(|c| c.to_string()),
+            ..Symbol::new(SymbolKind::Element, name, node)
         })
     }
 }
@@ -66,11 +188,25 @@ impl LanguageVisitor for JsVisitor {
             "function_declaration" | "function" => self.extract_function(node, source_code),
             "class_declaration" => self.extract_class(node, source_code),
             "variable_declarator" => self.extract_variable(node, source_code),
+            "call_expression" => self.extract_custom_element(node, source_code),
+            "export_statement" => {
+                self.collect_exports(node, source_code);
+                None
+            }
             _ => None,
         };
 
-        if let Some(symbol) = maybe_symbol {
+        if let Some(mut symbol) = maybe_symbol {
+            if node.kind() != "call_expression" {
+                symbol.visibility = Some(Self::visibility(node));
+            }
+            // Members follow the class they belong to
+            let members = match node.kind() {
+                "class_declaration" => self.extract_members(node, source_code, &symbol.name),
+                _ => Vec::new(),
+            };
             self.symbols.push(symbol);
+            self.symbols.extend(members);
         }
 
         // Recurse into children
@@ -81,7 +217,12 @@ impl LanguageVisitor for JsVisitor {
         }
     }
 
-    fn get_symbols(self) -> Vec<Symbol> {
+    fn get_symbols(mut self) -> Vec<Symbol> {
+        for symbol in &mut self.symbols {
+            if symbol.container.is_none() && self.exported.contains(&symbol.name) {
+                symbol.visibility = Some(Visibility::Exported);
+            }
+        }
         self.symbols
     }
 }
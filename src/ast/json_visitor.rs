@@ -0,0 +1,67 @@
+use super::key_path;
+use super::symbol::Symbol;
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct JsonVisitor {
+    symbols: Vec<Symbol>,
+    /// Keys (and array indices) leading to the current value.
+    path: Vec<String>,
+}
+
+impl JsonVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            path: Vec::new(),
+        }
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for JsonVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "pair" => {
+                let Some(key) = node.child_by_field_name("key") else {
+                    return;
+                };
+                let Ok(text) = key.utf8_text(source_code.as_bytes()) else {
+                    return;
+                };
+                let name = text.trim_matches('"').to_string();
+                let value = node.child_by_field_name("value");
+
+                self.symbols.push(key_path::key_symbol(&self.path, name.clone(), &key, node, value.as_ref()));
+                if let Some(value) = value {
+                    self.path.push(name);
+                    self.visit(&value, source_code);
+                    self.path.pop();
+                }
+            }
+            "array" => {
+                let mut cursor = node.walk();
+                let items: Vec<Node> = node.named_children(&mut cursor)
+                    .filter(|c| c.kind() != "comment")
+                    .collect();
+                for (index, item) in items.iter().enumerate() {
+                    self.path.push(format!("[{}]", index));
+                    self.visit(item, source_code);
+                    self.path.pop();
+                }
+            }
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,44 @@
+use super::symbol::{Symbol, SymbolKind};
+use tree_sitter::Node;
+
+/// Join key path segments with dots, attaching array indices directly to
+/// the preceding key: `["services", "api", "ports", "[0]"]` becomes
+/// `services.api.ports[0]`.
+pub fn join(segments: &[String]) -> String {
+    let mut path = String::new();
+    for segment in segments {
+        if !path.is_empty() && !segment.starts_with('[') {
+            path.push('.');
+        }
+        path.push_str(segment);
+    }
+    path
+}
+
+/// A configuration key at `parent` (the path of the enclosing keys) named
+/// `name`, spanning the whole entry `node` including its nested value.
+pub fn key_symbol(parent: &[String], name: String, name_node: &Node, node: &Node, value: Option<&Node>) -> Symbol {
+    let container = if parent.is_empty() {
+        None
+    } else {
+        Some(join(parent))
+    };
+    let qualified_name = match &container {
+        Some(container) => join(&[container.clone(), name.clone()]),
+        None => name.clone(),
+    };
+
+    let mut symbol = Symbol {
+        name_range: Some(name_node.byte_range()),
+        body_range: value.map(|n| n.byte_range()),
+        container,
+        qualified_name: Some(qualified_name),
+        ..Symbol::new(SymbolKind::Property, name, node)
+    };
+    // Indentation-based entries (YAML blocks) end after their final newline
+    let end = node.end_position();
+    if end.column == 0 && end.row > node.start_position().row {
+        symbol.end_line = end.row;
+    }
+    symbol
+}
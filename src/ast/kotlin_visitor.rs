@@ -0,0 +1,234 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct KotlinVisitor {
+    symbols: Vec<Symbol>,
+    /// Package followed by the names of the enclosing classes and objects.
+    scope: Vec<String>,
+    /// Number of enclosing class-like declarations, to tell methods from
+    /// top-level functions.
+    type_depth: usize,
+}
+
+impl KotlinVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+            type_depth: 0,
+        }
+    }
+
+    fn scoped_symbol(&self, kind: SymbolKind, name: String, container: Option<String>, node: &Node, source: &str) -> Symbol {
+        Symbol {
+            decorators: Self::annotations(node, source),
+            ..visitor::scoped_symbol(kind, name, container, ".", node)
+        }
+    }
+
+    /// Annotations among the declaration's modifiers, without their `@` or
+    /// use-site target: `JvmName("y")` for `@get:JvmName("y")`.
+    fn annotations(node: &Node, source: &str) -> Vec<String> {
+        let mut cursor = node.walk();
+        let Some(modifiers) = node.children(&mut cursor).find(|c| c.kind() == "modifiers") else {
+            return Vec::new();
+        };
+        let mut cursor = modifiers.walk();
+        modifiers.children(&mut cursor)
+            .filter(|c| c.kind() == "annotation")
+            .filter_map(|annotation| {
+                let target = annotation.named_child(annotation.named_child_count().checked_sub(1)?)?;
+                target.utf8_text(source.as_bytes()).ok()
+            })
+            .map(|text| text.to_string())
+            .collect()
+    }
+
+    fn container(&self) -> Option<String> {
+        visitor::scope_container(&self.scope, ".")
+    }
+
+    /// The receiver of an extension (`fun List<T>.second()`), written as a
+    /// type before the declared name. Type arguments and nullability are
+    /// dropped so the container is the plain extended type.
+    fn receiver_type(node: &Node, name_node: &Node, source: &str) -> Option<String> {
+        let mut cursor = node.walk();
+        let receiver = node.children(&mut cursor)
+            .take_while(|child| child.start_byte() < name_node.start_byte())
+            .filter(|child| matches!(child.kind(), "user_type" | "nullable_type" | "parenthesized_type"))
+            .last()?;
+        let text = receiver.utf8_text(source.as_bytes()).ok()?;
+        let base = text.split('<').next().unwrap_or(text);
+        Some(base.trim_end_matches('?').trim().to_string())
+    }
+
+    fn type_kind(node: &Node, source: &str) -> SymbolKind {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                "interface" => return SymbolKind::Interface,
+                "modifiers" => {
+                    let mut modifiers = child.walk();
+                    let is_enum = child.children(&mut modifiers)
+                        .any(|m| m.kind() == "class_modifier" && m.utf8_text(source.as_bytes()) == Ok("enum"));
+                    if is_enum {
+                        return SymbolKind::Enum;
+                    }
+                }
+                _ => {}
+            }
+        }
+        SymbolKind::Class
+    }
+
+    fn extract_type(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let kind = if node.kind() == "class_declaration" {
+            Self::type_kind(node, source)
+        } else {
+            SymbolKind::Class
+        };
+
+        let name_node = node.child_by_field_name("name").or_else(|| {
+            let mut cursor = node.walk();
+            node.children(&mut cursor).find(|c| c.kind() == "identifier")
+        });
+        let name = match name_node {
+            Some(name_node) => name_node.utf8_text(source.as_bytes()).ok()?.to_string(),
+            // `companion object { ... }` without an explicit name
+            None if node.kind() == "companion_object" => "Companion".to_string(),
+            None => return None,
+        };
+
+        let mut cursor = node.walk();
+        let body = node.children(&mut cursor).find(|c| matches!(c.kind(), "class_body" | "enum_class_body"));
+
+        Some(Symbol {
+            name_range: name_node.map(|n| n.byte_range()),
+            body_range: body.map(|n| n.byte_range()),
+            ..self.scoped_symbol(kind, name, self.container(), node, source)
+        })
+    }
+
+    fn extract_function(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+        let receiver = Self::receiver_type(node, &name_node, source);
+        let kind = if self.type_depth > 0 {
+            SymbolKind::Method
+        } else {
+            SymbolKind::Function
+        };
+
+        let mut cursor = node.walk();
+        let body = node.children(&mut cursor).find(|c| c.kind() == "function_body");
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: body.map(|n| n.byte_range()),
+            ..self.scoped_symbol(kind, name, receiver.or_else(|| self.container()), node, source)
+        })
+    }
+
+    fn extract_property(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let mut cursor = node.walk();
+        let declaration = node.children(&mut cursor).find(|c| c.kind() == "variable_declaration")?;
+        let mut cursor = declaration.walk();
+        let name_node = declaration.children(&mut cursor).find(|c| c.kind() == "identifier")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+        let receiver = Self::receiver_type(node, &declaration, source);
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..self.scoped_symbol(SymbolKind::Property, name, receiver.or_else(|| self.container()), node, source)
+        })
+    }
+
+    /// Primary constructor parameters declared with `val`/`var`.
+    fn extract_class_parameter(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let mut cursor = node.walk();
+        if !node.children(&mut cursor).any(|c| matches!(c.kind(), "val" | "var")) {
+            return None;
+        }
+        let mut cursor = node.walk();
+        let name_node = node.children(&mut cursor).find(|c| c.kind() == "identifier")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..self.scoped_symbol(SymbolKind::Property, name, self.container(), node, source)
+        })
+    }
+
+    fn extract_type_alias(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let mut cursor = node.walk();
+        let name_node = node.child_by_field_name("type")
+            .or_else(|| node.children(&mut cursor).find(|c| c.kind() == "identifier"))?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..self.scoped_symbol(SymbolKind::Type, name, self.container(), node, source)
+        })
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for KotlinVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "package_header" => {
+                let mut cursor = node.walk();
+                let package = node.children(&mut cursor)
+                    .find(|c| matches!(c.kind(), "qualified_identifier" | "identifier"))
+                    .and_then(|c| c.utf8_text(source_code.as_bytes()).ok());
+                if let Some(package) = package {
+                    self.scope = vec![package.to_string()];
+                }
+            }
+            "class_declaration" | "object_declaration" | "companion_object" => {
+                if let Some(symbol) = self.extract_type(node, source_code) {
+                    self.scope.push(symbol.name.clone());
+                    self.type_depth += 1;
+                    self.symbols.push(symbol);
+                    self.visit_children(node, source_code);
+                    self.type_depth -= 1;
+                    self.scope.pop();
+                }
+            }
+            "function_declaration" => {
+                // Local declarations inside function bodies are not symbols
+                if let Some(symbol) = self.extract_function(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "property_declaration" => {
+                if let Some(symbol) = self.extract_property(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "class_parameter" => {
+                if let Some(symbol) = self.extract_class_parameter(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "type_alias" => {
+                if let Some(symbol) = self.extract_type_alias(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,105 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct LuaVisitor {
+    symbols: Vec<Symbol>,
+}
+
+impl LuaVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+        }
+    }
+
+    /// Turn a function name expression into a symbol: `foo`, `M.foo` (table
+    /// function, container `M`) or `M:foo` (method with implicit `self`).
+    fn named_function(&self, name_node: &Node, node: &Node, body: Option<&Node>, source: &str) -> Option<Symbol> {
+        let (kind, table, field) = match name_node.kind() {
+            "identifier" => (SymbolKind::Function, None, *name_node),
+            "dot_index_expression" => (
+                SymbolKind::Function,
+                name_node.child_by_field_name("table"),
+                name_node.child_by_field_name("field")?,
+            ),
+            "method_index_expression" => (
+                SymbolKind::Method,
+                name_node.child_by_field_name("table"),
+                name_node.child_by_field_name("method")?,
+            ),
+            _ => return None,
+        };
+
+        let name = field.utf8_text(source.as_bytes()).ok()?.to_string();
+        let container = match table {
+            Some(table) => Some(table.utf8_text(source.as_bytes()).ok()?.to_string()),
+            None => None,
+        };
+
+        Some(Symbol {
+            name_range: Some(field.byte_range()),
+            body_range: body.map(|n| n.byte_range()),
+            container,
+            qualified_name: Some(name_node.utf8_text(source.as_bytes()).ok()?.to_string()),
+            ..Symbol::new(kind, name, node)
+        })
+    }
+
+    fn extract_function(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let body = node.child_by_field_name("body");
+        self.named_function(&name_node, node, body.as_ref(), source)
+    }
+
+    /// `local f = function() end` and `M.f = function() end`.
+    fn extract_assigned_functions(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut cursor = node.walk();
+        let Some(variables) = node.children(&mut cursor).find(|c| c.kind() == "variable_list") else {
+            return Vec::new();
+        };
+        let mut cursor = node.walk();
+        let Some(values) = node.children(&mut cursor).find(|c| c.kind() == "expression_list") else {
+            return Vec::new();
+        };
+
+        let mut names = variables.walk();
+        let mut exprs = values.walk();
+        variables.children_by_field_name("name", &mut names)
+            .zip(values.children_by_field_name("value", &mut exprs))
+            .filter(|(_, value)| value.kind() == "function_definition")
+            .filter_map(|(name_node, value)| {
+                let body = value.child_by_field_name("body");
+                self.named_function(&name_node, node, body.as_ref(), source)
+            })
+            .collect()
+    }
+}
+
+impl LanguageVisitor for LuaVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "function_declaration" => {
+                if let Some(symbol) = self.extract_function(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "assignment_statement" => {
+                let symbols = self.extract_assigned_functions(node, source_code);
+                self.symbols.extend(symbols);
+            }
+            _ => {}
+        }
+
+        // Recurse into children
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,119 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct MakeVisitor {
+    symbols: Vec<Symbol>,
+}
+
+impl MakeVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+        }
+    }
+
+    /// Make nodes take in the newlines (and blank lines) that end them, so
+    /// the last line is that of the last content instead.
+    fn make_symbol(kind: SymbolKind, name: String, node: &Node, source: &str) -> Symbol {
+        let text = &source[node.byte_range()];
+        Symbol {
+            end_line: node.start_position().row + 1 + text.trim_end().matches('\n').count(),
+            ..Symbol::new(kind, name, node)
+        }
+    }
+
+    /// Every target of a rule is a function, `app test: deps` defines
+    /// both `app` and `test`. Special targets such as `.PHONY` are skipped.
+    fn extract_targets(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut cursor = node.walk();
+        let Some(targets) = node.children(&mut cursor).find(|c| c.kind() == "targets") else {
+            return Vec::new();
+        };
+        let mut cursor = node.walk();
+        let recipe = node.children(&mut cursor).find(|c| c.kind() == "recipe");
+
+        let mut cursor = targets.walk();
+        targets.named_children(&mut cursor)
+            .filter_map(|target| {
+                let name = target.utf8_text(source.as_bytes()).ok()?;
+                if name.starts_with('.') {
+                    return None;
+                }
+                Some(Symbol {
+                    name_range: Some(target.byte_range()),
+                    body_range: recipe.map(|n| n.byte_range()),
+                    ..Self::make_symbol(SymbolKind::Function, name.to_string(), node, source)
+                })
+            })
+            .collect()
+    }
+
+    /// `CC := gcc` and `define HELP ... endef`. Appends (`CFLAGS += -g`)
+    /// and target-specific assignments change a variable defined elsewhere.
+    fn extract_variable(&self, node: &Node, source: &str) -> Option<Symbol> {
+        if node.child_by_field_name("target_or_pattern").is_some() {
+            return None;
+        }
+        if node.child_by_field_name("operator").is_some_and(|op| op.kind() == "+=") {
+            return None;
+        }
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("value").map(|n| n.byte_range()),
+            ..Self::make_symbol(SymbolKind::Variable, name, node, source)
+        })
+    }
+
+    /// `include a.mk b.mk` (or `-include`/`sinclude`) imports each file.
+    fn extract_includes(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let Some(filenames) = node.child_by_field_name("filenames") else {
+            return Vec::new();
+        };
+        let mut cursor = filenames.walk();
+        filenames.named_children(&mut cursor)
+            .filter_map(|file| {
+                let name = file.utf8_text(source.as_bytes()).ok()?.to_string();
+                Some(Symbol {
+                    name_range: Some(file.byte_range()),
+                    ..Self::make_symbol(SymbolKind::Import, name, node, source)
+                })
+            })
+            .collect()
+    }
+}
+
+impl LanguageVisitor for MakeVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "rule" => {
+                let targets = self.extract_targets(node, source_code);
+                self.symbols.extend(targets);
+            }
+            "variable_assignment" | "define_directive" => {
+                if let Some(symbol) = self.extract_variable(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "include_directive" => {
+                let includes = self.extract_includes(node, source_code);
+                self.symbols.extend(includes);
+            }
+            _ => {
+                // Definitions may be nested in conditionals or `export`/`override`
+                for i in 0..node.child_count() {
+                    if let Some(child) = node.child(i) {
+                        self.visit(&child, source_code);
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,136 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct MarkdownVisitor {
+    symbols: Vec<Symbol>,
+    /// Titles of the enclosing sections, outermost first.
+    headings: Vec<String>,
+}
+
+impl MarkdownVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            headings: Vec::new(),
+        }
+    }
+
+    /// Sections are qualified by their heading path, `Guide > Install`.
+    fn scoped_symbol(&self, kind: SymbolKind, name: String, node: &Node) -> Symbol {
+        let container = if self.headings.is_empty() {
+            None
+        } else {
+            Some(self.headings.join(" > "))
+        };
+        let qualified_name = match &container {
+            Some(container) => format!("{} > {}", container, name),
+            None => name.clone(),
+        };
+
+        Symbol {
+            container,
+            qualified_name: Some(qualified_name),
+            ..Symbol::new(kind, name, node)
+        }
+    }
+
+    /// Last line with content, so trailing blank lines before the next
+    /// heading are not part of a section.
+    fn content_end_line(node: &Node, source: &str) -> usize {
+        let text = &source[node.byte_range()];
+        node.start_position().row + 1 + text.trim_end().matches('\n').count()
+    }
+
+    /// `# Title` headings open a section that the grammar nests by level.
+    fn visit_section(&mut self, node: &Node, source_code: &str) {
+        let heading = node.named_child(0).filter(|h| h.kind() == "atx_heading");
+        let title = heading.and_then(|h| h.child_by_field_name("heading_content"));
+        let Some(title) = title else {
+            self.visit_children(node, source_code);
+            return;
+        };
+        let Ok(text) = title.utf8_text(source_code.as_bytes()) else {
+            return;
+        };
+        let name = text.trim().to_string();
+
+        self.symbols.push(Symbol {
+            name_range: Some(title.byte_range()),
+            end_line: Self::content_end_line(node, source_code),
+            ..self.scoped_symbol(SymbolKind::Section, name.clone(), node)
+        });
+        self.headings.push(name);
+        self.visit_children(node, source_code);
+        self.headings.pop();
+    }
+
+    /// Setext headings (`Title` underlined with `===`) are not wrapped in
+    /// sections by the grammar, so they only span the heading itself.
+    fn extract_setext_heading(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let title = node.child_by_field_name("heading_content")?;
+        let name = title.utf8_text(source.as_bytes()).ok()?.trim().to_string();
+
+        Some(Symbol {
+            name_range: Some(title.byte_range()),
+            end_line: Self::content_end_line(node, source),
+            ..self.scoped_symbol(SymbolKind::Section, name, node)
+        })
+    }
+
+    /// Fenced code blocks are named after the language of their info string
+    /// (`code` when there is none); the body range covers the fenced content.
+    fn extract_code_block(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let mut cursor = node.walk();
+        let info = node.children(&mut cursor).find(|c| c.kind() == "info_string");
+        let language = info.and_then(|info| {
+            let mut cursor = info.walk();
+            info.children(&mut cursor).find(|c| c.kind() == "language")
+        });
+        let name = match language {
+            Some(language) => language.utf8_text(source.as_bytes()).ok()?.to_string(),
+            None => "code".to_string(),
+        };
+
+        let mut cursor = node.walk();
+        let content = node.children(&mut cursor).find(|c| c.kind() == "code_fence_content");
+
+        Some(Symbol {
+            name_range: language.map(|n| n.byte_range()),
+            body_range: content.map(|n| n.byte_range()),
+            end_line: Self::content_end_line(node, source),
+            ..self.scoped_symbol(SymbolKind::CodeBlock, name, node)
+        })
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for MarkdownVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "section" => self.visit_section(node, source_code),
+            "setext_heading" => {
+                if let Some(symbol) = self.extract_setext_heading(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "fenced_code_block" => {
+                if let Some(symbol) = self.extract_code_block(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
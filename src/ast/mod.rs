@@ -1,19 +1,110 @@
 pub mod symbol;
 pub mod visitor;
 pub mod parser;
+pub mod bash_visitor;
+pub mod c_visitor;
+pub mod component_visitor;
 pub mod cpp_visitor;
+pub mod csharp_visitor;
+pub mod dockerfile_visitor;
+pub mod elixir_visitor;
 pub mod python_visitor;
 pub mod js_visitor;
 pub mod go_visitor;
+pub mod graphql_visitor;
+pub mod haskell_visitor;
+pub mod hcl_visitor;
+pub mod html_visitor;
+pub mod java_visitor;
+pub mod json_visitor;
+pub mod key_path;
+pub mod kotlin_visitor;
+pub mod lua_visitor;
+pub mod make_visitor;
+pub mod markdown_visitor;
+pub mod notebook;
+pub mod php_visitor;
+pub mod preprocessor;
+pub mod proto_visitor;
+pub mod ruby_visitor;
+pub mod rust_visitor;
+pub mod scala_visitor;
+pub mod sql_visitor;
+pub mod stylesheet_scanner;
+pub mod swift_visitor;
+pub mod thrift_scanner;
+pub mod toml_visitor;
+pub mod yaml_visitor;
+pub mod zig_visitor;
 
+#[cfg(test)]
+mod tests_bash;
+#[cfg(test)]
+mod tests_c;
+#[cfg(test)]
+mod tests_component;
 #[cfg(test)]
 mod tests_cpp;
 #[cfg(test)]
+mod tests_csharp;
+#[cfg(test)]
+mod tests_dockerfile;
+#[cfg(test)]
+mod tests_elixir;
+#[cfg(test)]
 mod tests_python;
 #[cfg(test)]
 mod tests_js;
 #[cfg(test)]
 mod tests_go;
+#[cfg(test)]
+mod tests_graphql;
+#[cfg(test)]
+mod tests_haskell;
+#[cfg(test)]
+mod tests_hcl;
+#[cfg(test)]
+mod tests_html;
+#[cfg(test)]
+mod tests_java;
+#[cfg(test)]
+mod tests_json;
+#[cfg(test)]
+mod tests_kotlin;
+#[cfg(test)]
+mod tests_lua;
+#[cfg(test)]
+mod tests_make;
+#[cfg(test)]
+mod tests_markdown;
+#[cfg(test)]
+mod tests_notebook;
+#[cfg(test)]
+mod tests_php;
+#[cfg(test)]
+mod tests_proto;
+#[cfg(test)]
+mod tests_ruby;
+#[cfg(test)]
+mod tests_rust;
+#[cfg(test)]
+mod tests_scala;
+#[cfg(test)]
+mod tests_sql;
+#[cfg(test)]
+mod tests_stylesheet;
+#[cfg(test)]
+mod tests_swift;
+#[cfg(test)]
+mod tests_thrift;
+#[cfg(test)]
+mod tests_toml;
+#[cfg(test)]
+mod tests_yaml;
+#[cfg(test)]
+mod tests_zig;
 
-pub use symbol::SymbolKind;
-pub use parser::{CodeParser, get_language};
+pub use symbol::{SymbolKind, Visibility};
+pub use parser::{CodeParser, HeaderLanguage};
+#[cfg(test)]
+pub use parser::{get_language, get_script_language};
@@ -0,0 +1,141 @@
+//! Jupyter notebooks are JSON documents holding a list of cells. The cells
+//! are joined into a single Python source in which non-code cells are
+//! blanked out, so a symbol's lines and byte ranges refer to that joined
+//! source; `cell_index` and `cell_line` locate it in the notebook itself.
+
+use super::parser::CodeParser;
+use super::symbol::{Symbol, SymbolKind};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Notebook {
+    cells: Vec<Cell>,
+}
+
+#[derive(Deserialize)]
+struct Cell {
+    cell_type: String,
+    #[serde(default)]
+    source: CellSource,
+}
+
+/// Cell sources are stored either as one string or as a list of lines.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CellSource {
+    Lines(Vec<String>),
+    Text(String),
+}
+
+impl Default for CellSource {
+    fn default() -> Self {
+        CellSource::Text(String::new())
+    }
+}
+
+impl CellSource {
+    fn text(&self) -> String {
+        match self {
+            CellSource::Lines(lines) => lines.concat(),
+            CellSource::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// Where a cell starts in the joined source.
+struct CellSpan {
+    first_line: usize,
+    line_count: usize,
+    offset: usize,
+}
+
+/// IPython magics (`%matplotlib inline`, `!pip install`) are not Python;
+/// they are commented out in place. Cell magics such as `%%bash` make the
+/// whole cell foreign.
+fn python_source(text: &str) -> Option<String> {
+    if text.trim_start().starts_with("%%") {
+        return None;
+    }
+    let lines: Vec<String> = text.split('\n')
+        .map(|line| {
+            let indent = line.len() - line.trim_start().len();
+            match line[indent..].chars().next() {
+                Some('%' | '!') => format!("{}#{}", &line[..indent], &line[indent + 1..]),
+                _ => line.to_string(),
+            }
+        })
+        .collect();
+    Some(lines.join("\n"))
+}
+
+/// Replace everything but line breaks, keeping lines and byte offsets.
+fn blank(text: &str) -> String {
+    let mut blanked = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\n' => blanked.push('\n'),
+            _ => blanked.extend(std::iter::repeat_n(' ', c.len_utf8())),
+        }
+    }
+    blanked
+}
+
+pub fn extract_symbols(source: &str, headings: bool) -> Result<Vec<Symbol>, String> {
+    let notebook: Notebook = serde_json::from_str(source)
+        .map_err(|e| format!("Failed to parse notebook: {}", e))?;
+
+    let mut joined = String::new();
+    let mut spans = Vec::with_capacity(notebook.cells.len());
+    let mut markdown = Vec::new();
+    for (index, cell) in notebook.cells.iter().enumerate() {
+        let mut text = cell.source.text();
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        spans.push(CellSpan {
+            first_line: joined.matches('\n').count() + 1,
+            line_count: text.matches('\n').count(),
+            offset: joined.len(),
+        });
+
+        match cell.cell_type.as_str() {
+            "code" => match python_source(&text) {
+                Some(python) => joined.push_str(&python),
+                None => joined.push_str(&blank(&text)),
+            },
+            "markdown" if headings => {
+                joined.push_str(&blank(&text));
+                markdown.push((index, text));
+            }
+            _ => joined.push_str(&blank(&text)),
+        }
+    }
+
+    let mut parser = CodeParser::new(tree_sitter_python::LANGUAGE.into())?;
+    let mut symbols = parser.extract_symbols(&joined, "notebook.py", None)?;
+
+    for (index, text) in markdown {
+        let span = &spans[index];
+        let mut parser = CodeParser::new(tree_sitter_md::LANGUAGE.into())?;
+        let sections = parser.extract_symbols(&text, "cell.md", Some([SymbolKind::Section].into()))?;
+        let shift = |range: std::ops::Range<usize>| range.start + span.offset..range.end + span.offset;
+        symbols.extend(sections.into_iter().map(|s| Symbol {
+            start_line: s.start_line + span.first_line - 1,
+            end_line: s.end_line + span.first_line - 1,
+            full_range: shift(s.full_range),
+            name_range: s.name_range.map(shift),
+            body_range: s.body_range.map(shift),
+            ..s
+        }));
+    }
+    symbols.sort_by_key(|s| s.start_line);
+
+    for symbol in &mut symbols {
+        let cell = spans.iter().rposition(|span| span.line_count > 0 && span.first_line <= symbol.start_line);
+        if let Some(index) = cell {
+            symbol.cell_index = Some(index);
+            symbol.cell_line = Some(symbol.start_line - spans[index].first_line + 1);
+        }
+    }
+    Ok(symbols)
+}
@@ -1,14 +1,77 @@
-use super::symbol::{Symbol, SymbolKind};
+use super::symbol::{self, Symbol, SymbolKind};
 use super::visitor::{LanguageVisitor};
+use super::bash_visitor::BashVisitor;
+use super::c_visitor::CVisitor;
+use super::component_visitor::ComponentVisitor;
 use super::cpp_visitor::CppVisitor;
+use super::csharp_visitor::CSharpVisitor;
+use super::dockerfile_visitor::DockerfileVisitor;
+use super::elixir_visitor::ElixirVisitor;
 use super::python_visitor::PythonVisitor;
 use super::js_visitor::JsVisitor;
 use super::go_visitor::GoVisitor;
-use std::collections::HashSet;
+use super::graphql_visitor::GraphqlVisitor;
+use super::haskell_visitor::HaskellVisitor;
+use super::hcl_visitor::HclVisitor;
+use super::html_visitor::HtmlVisitor;
+use super::java_visitor::JavaVisitor;
+use super::json_visitor::JsonVisitor;
+use super::kotlin_visitor::KotlinVisitor;
+use super::lua_visitor::LuaVisitor;
+use super::make_visitor::MakeVisitor;
+use super::markdown_visitor::MarkdownVisitor;
+use super::notebook;
+use super::php_visitor::PhpVisitor;
+use super::preprocessor;
+use super::proto_visitor::ProtoVisitor;
+use super::ruby_visitor::RubyVisitor;
+use super::rust_visitor::RustVisitor;
+use super::scala_visitor::ScalaVisitor;
+use super::sql_visitor::SqlVisitor;
+use super::stylesheet_scanner;
+use super::swift_visitor::SwiftVisitor;
+use super::thrift_scanner;
+use super::toml_visitor::TomlVisitor;
+use super::yaml_visitor::YamlVisitor;
+use super::zig_visitor::ZigVisitor;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
 use tree_sitter::{Language, Parser};
 
+/// Constructs that only appear in C++ headers. `extern "C"` guards are
+/// deliberately not listed since they are common in plain C headers.
+static CPP_HEADER_MARKERS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?m)^\s*(namespace\s+\w*\s*\{|template\s*<|class\s+\w+\s*[:{;]|using\s+\w+|(public|private|protected)\s*:)|\bstd::|\bvirtual\s+\w",
+    )
+    .unwrap()
+});
+
+/// Language `.h` headers are parsed as, when it should not be guessed
+/// from their content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HeaderLanguage {
+    C,
+    Cpp,
+}
+
 pub struct CodeParser {
     parser: Parser,
+    /// Also extract symbols from Markdown code fences in supported languages.
+    code_fences: bool,
+    /// Also report the headings of Markdown cells in Jupyter notebooks.
+    notebook_headings: bool,
+    /// Also report variables assigned inside Python functions.
+    python_locals: bool,
+    /// Only keep the public API surface.
+    public_only: bool,
+    /// Macros defined for the build, to skip C and C++ `#if` branches it
+    /// leaves out.
+    defines: Option<HashMap<String, String>>,
+    /// Language of `.h` headers; `None` guesses it from their content.
+    header_language: Option<HeaderLanguage>,
 }
 
 impl CodeParser {
@@ -17,7 +80,60 @@ impl CodeParser {
         parser.set_language(&language)
             .map_err(|e| format!("Failed to set language: {}", e))?;
         
-        Ok(Self { parser })
+        Ok(Self { parser, code_fences: false, notebook_headings: false, python_locals: false, public_only: false, defines: None, header_language: None })
+    }
+
+    /// Parser for any supported file: by extension, by `#!` line for
+    /// extensionless scripts, or without a grammar for formats that are
+    /// scanned rather than parsed.
+    pub fn for_file(file_path: &std::path::Path) -> Option<Self> {
+        let language = get_language(&file_path.to_string_lossy()).or_else(|| get_script_language(file_path));
+        match language {
+            Some(language) => Self::new(language).ok(),
+            None if file_path.extension().and_then(|ext| ext.to_str()).is_some_and(is_scanned_extension) => {
+                Some(Self { parser: Parser::new(), code_fences: false, notebook_headings: false, python_locals: false, public_only: false, defines: None, header_language: None })
+            }
+            None => None,
+        }
+    }
+
+    pub fn set_code_fences(&mut self, enabled: bool) {
+        self.code_fences = enabled;
+    }
+
+    pub fn set_notebook_headings(&mut self, enabled: bool) {
+        self.notebook_headings = enabled;
+    }
+
+    pub fn set_python_locals(&mut self, enabled: bool) {
+        self.python_locals = enabled;
+    }
+
+    pub fn set_public_only(&mut self, enabled: bool) {
+        self.public_only = enabled;
+    }
+
+    /// `NAME` or `NAME=VALUE` definitions, as given to `-D`; `None` keeps
+    /// every branch.
+    pub fn set_defines(&mut self, defines: Option<&[String]>) {
+        self.defines = defines.map(preprocessor::parse_defines);
+    }
+
+    pub fn set_header_language(&mut self, language: Option<HeaderLanguage>) {
+        self.header_language = language;
+    }
+
+    /// Whether a `.h` file is C++: as configured, or else guessed.
+    fn is_cpp_header(&self, source_code: &str) -> bool {
+        match self.header_language {
+            Some(language) => language == HeaderLanguage::Cpp,
+            None => is_cpp_header(source_code),
+        }
+    }
+
+    fn set_language(&mut self, language: Language) -> Result<(), String> {
+        self.parser.set_language(&language)
+            .map_err(|e| format!("Failed to set language: {}", e))
     }
 
     pub fn parse_with_visitor<V: LanguageVisitor>(
@@ -38,18 +154,33 @@ impl CodeParser {
         file_path: &str,
         filter: Option<HashSet<SymbolKind>>,
     ) -> Result<Vec<Symbol>, String> {
-        let ext = std::path::Path::new(file_path)
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .unwrap_or("");
-        
+        let ext = match file_extension(std::path::Path::new(file_path)) {
+            Some(ext) => ext,
+            None => shebang_extension(source_code).unwrap_or(""),
+        };
+
         let mut symbols = match ext {
-            "cpp" | "cc" | "cxx" | "c" | "h" | "hpp" => {
-                let visitor = CppVisitor::new();
+            "h" if !self.is_cpp_header(source_code) => {
+                self.set_language(tree_sitter_c::LANGUAGE.into())?;
+                let visitor = CVisitor::new().with_defines(self.defines.clone());
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "c" => {
+                self.set_language(tree_sitter_c::LANGUAGE.into())?;
+                let visitor = CVisitor::new().with_defines(self.defines.clone());
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "cpp" | "cc" | "cxx" | "h" | "hpp" => {
+                self.set_language(tree_sitter_cpp::LANGUAGE.into())?;
+                let visitor = CppVisitor::new().with_defines(self.defines.clone());
                 self.parse_with_visitor(source_code, visitor)?
             }
             "py" => {
-                let visitor = PythonVisitor::new();
+                let visitor = PythonVisitor::new().with_locals(self.python_locals);
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "rs" => {
+                let visitor = RustVisitor::new();
                 self.parse_with_visitor(source_code, visitor)?
             }
             "js" | "ts" => {
@@ -60,12 +191,131 @@ impl CodeParser {
                 let visitor = GoVisitor::new();
                 self.parse_with_visitor(source_code, visitor)?
             }
+            "java" => {
+                let visitor = JavaVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "cs" => {
+                let visitor = CSharpVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "rb" | "rake" => {
+                let visitor = RubyVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "php" | "phtml" | "inc" => {
+                let visitor = PhpVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "kt" | "kts" => {
+                let visitor = KotlinVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "swift" => {
+                let visitor = SwiftVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "sh" | "bash" | "zsh" => {
+                let visitor = BashVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "lua" => {
+                let visitor = LuaVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "zig" => {
+                let visitor = ZigVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "hs" => {
+                let visitor = HaskellVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "scala" | "sc" => {
+                let visitor = ScalaVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "ex" | "exs" => {
+                let visitor = ElixirVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "json" => {
+                let visitor = JsonVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "yaml" | "yml" => {
+                let visitor = YamlVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "toml" => {
+                let visitor = TomlVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "proto" => {
+                let visitor = ProtoVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "graphql" | "gql" => {
+                let visitor = GraphqlVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "thrift" => thrift_scanner::scan(source_code),
+            "ipynb" => notebook::extract_symbols(source_code, self.notebook_headings)?,
+            "sql" => {
+                let visitor = SqlVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "tf" | "hcl" => {
+                let visitor = HclVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "dockerfile" | "containerfile" => {
+                let visitor = DockerfileVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "mk" | "mak" => {
+                let visitor = MakeVisitor::new();
+                self.parse_with_visitor(source_code, visitor)?
+            }
+            "html" | "htm" => {
+                let visitor = HtmlVisitor::new();
+                let symbols = self.parse_with_visitor(source_code, visitor)?;
+                with_embedded_symbols(symbols, source_code)
+            }
+            // Stylesheets are scanned rather than parsed, see `stylesheet_scanner`
+            "css" => stylesheet_scanner::scan(source_code, false),
+            "scss" | "less" => stylesheet_scanner::scan(source_code, true),
+            // Single-file components are parsed as HTML, with the symbols of
+            // their `<script>` injected
+            "vue" | "svelte" => {
+                let component = std::path::Path::new(file_path)
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .unwrap_or("component");
+                let visitor = ComponentVisitor::new(component);
+                let symbols = self.parse_with_visitor(source_code, visitor)?;
+                with_embedded_symbols(symbols, source_code)
+            }
+            "md" | "markdown" => {
+                let visitor = MarkdownVisitor::new();
+                let symbols = self.parse_with_visitor(source_code, visitor)?;
+                if self.code_fences {
+                    with_embedded_symbols(symbols, source_code)
+                } else {
+                    symbols
+                }
+            }
             _ => {
                 let visitor = PythonVisitor::new(); // fallback
                 self.parse_with_visitor(source_code, visitor)?
             }
         };
 
+        // Containers are needed to tell what is public, so this goes first
+        if self.public_only {
+            symbol::retain_public(&mut symbols);
+        }
+
         // Apply filtering
         if let Some(filter) = filter {
             symbols.retain(|s| filter.contains(&s.kind));
@@ -76,17 +326,180 @@ impl CodeParser {
 }
 
 pub fn get_language(file_path: &str) -> Option<Language> {
-    let ext = std::path::Path::new(file_path)
-        .extension()
-        .and_then(|ext| ext.to_str())
-        .unwrap_or("");
-    
+    let ext = file_extension(std::path::Path::new(file_path)).unwrap_or("");
+
+    language_for_extension(ext)
+}
+
+/// Extension a file is handled by. Build files are recognised by name
+/// instead: `Dockerfile.prod` is a Dockerfile and `GNUmakefile` a Makefile.
+fn file_extension(file_path: &std::path::Path) -> Option<&str> {
+    let name = file_path.file_name()?.to_str()?;
+    match name.split_once('.').map_or(name, |(stem, _)| stem) {
+        "Dockerfile" | "dockerfile" | "Containerfile" => return Some("dockerfile"),
+        "Makefile" | "makefile" | "GNUmakefile" => return Some("mk"),
+        _ => {}
+    }
+    file_path.extension()?.to_str()
+}
+
+/// Language of an extensionless script, read from its `#!` line.
+pub fn get_script_language(file_path: &std::path::Path) -> Option<Language> {
+    use std::io::BufRead;
+
+    if file_path.extension().is_some() {
+        return None;
+    }
+    let file = std::fs::File::open(file_path).ok()?;
+    let mut first_line = String::new();
+    std::io::BufReader::new(file).read_line(&mut first_line).ok()?;
+    language_for_extension(shebang_extension(&first_line)?)
+}
+
+/// Map the interpreter named in a `#!` line to the extension its scripts
+/// conventionally use, e.g. `#!/usr/bin/env bash` to `sh`.
+fn shebang_extension(source_code: &str) -> Option<&'static str> {
+    let line = source_code.lines().next()?.strip_prefix("#!")?;
+    let mut words = line.split_whitespace();
+    let mut interpreter = words.next()?.rsplit('/').next()?;
+    if interpreter == "env" {
+        interpreter = words.find(|w| !w.starts_with('-'))?;
+    }
+
+    match interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.') {
+        "sh" | "bash" | "zsh" | "ksh" | "dash" => Some("sh"),
+        "python" => Some("py"),
+        "ruby" => Some("rb"),
+        "node" => Some("js"),
+        "php" => Some("php"),
+        _ => None,
+    }
+}
+
+/// Map a code fence info string to the extension of its language, e.g.
+/// ```` ```python ```` to `py`.
+fn fence_extension(language: &str) -> Option<&'static str> {
+    match language.to_ascii_lowercase().as_str() {
+        "c" => Some("c"),
+        "cpp" | "c++" | "cxx" => Some("cpp"),
+        "python" | "py" => Some("py"),
+        "rust" | "rs" => Some("rs"),
+        "javascript" | "js" => Some("js"),
+        "typescript" | "ts" => Some("ts"),
+        "go" | "golang" => Some("go"),
+        "java" => Some("java"),
+        "csharp" | "cs" | "c#" => Some("cs"),
+        "ruby" | "rb" => Some("rb"),
+        "php" => Some("php"),
+        "kotlin" | "kt" => Some("kt"),
+        "swift" => Some("swift"),
+        "bash" | "sh" | "shell" | "zsh" => Some("sh"),
+        "lua" => Some("lua"),
+        "zig" => Some("zig"),
+        "haskell" | "hs" => Some("hs"),
+        "scala" => Some("scala"),
+        "elixir" | "ex" => Some("ex"),
+        "json" => Some("json"),
+        "yaml" | "yml" => Some("yaml"),
+        "toml" => Some("toml"),
+        "html" => Some("html"),
+        "css" => Some("css"),
+        "scss" => Some("scss"),
+        "less" => Some("less"),
+        _ => None,
+    }
+}
+
+/// Insert the symbols of each code block in a supported language (a
+/// Markdown code fence or a component's `<script>`) after the block
+/// itself, with lines and byte ranges mapped back to the enclosing file.
+fn with_embedded_symbols(symbols: Vec<Symbol>, source_code: &str) -> Vec<Symbol> {
+    let mut result = Vec::with_capacity(symbols.len());
+    for symbol in symbols {
+        let block = match (&symbol.kind, &symbol.body_range) {
+            (SymbolKind::CodeBlock, Some(content)) => fence_extension(&symbol.name).map(|ext| (ext, content.clone())),
+            _ => None,
+        };
+        result.push(symbol);
+
+        let Some((ext, content)) = block else {
+            continue;
+        };
+        let file_name = format!("embedded.{}", ext);
+        let Some(mut parser) = CodeParser::for_file(std::path::Path::new(&file_name)) else {
+            continue;
+        };
+        let Ok(embedded) = parser.extract_symbols(&source_code[content.clone()], &file_name, None) else {
+            continue;
+        };
+
+        let line_offset = source_code[..content.start].matches('\n').count();
+        let shift = |range: std::ops::Range<usize>| range.start + content.start..range.end + content.start;
+        result.extend(embedded.into_iter().map(|s| Symbol {
+            start_line: s.start_line + line_offset,
+            end_line: s.end_line + line_offset,
+            full_range: shift(s.full_range),
+            name_range: s.name_range.map(shift),
+            body_range: s.body_range.map(shift),
+            ..s
+        }));
+    }
+    result
+}
+
+/// Formats without a usable tree-sitter grammar of their own, handled by
+/// a scanner (Thrift, stylesheets) or decoded first (Jupyter notebooks).
+fn is_scanned_extension(ext: &str) -> bool {
+    matches!(ext, "thrift" | "css" | "scss" | "less" | "ipynb")
+}
+
+fn language_for_extension(ext: &str) -> Option<Language> {
     match ext {
-        "cpp" | "cc" | "cxx" | "c" | "h" | "hpp" => Some(tree_sitter_cpp::LANGUAGE.into()),
+        "c" => Some(tree_sitter_c::LANGUAGE.into()),
+        // `.h` may be C or C++; `CodeParser::extract_symbols` refines this
+        // once the file content is known.
+        "cpp" | "cc" | "cxx" | "h" | "hpp" => Some(tree_sitter_cpp::LANGUAGE.into()),
         "py" => Some(tree_sitter_python::LANGUAGE.into()),
         "rs" => Some(tree_sitter_rust::LANGUAGE.into()),
-        "js" | "ts" => Some(tree_sitter_javascript::LANGUAGE.into()),
+        "js" => Some(tree_sitter_javascript::LANGUAGE.into()),
+        // TypeScript has its own grammar for type annotations, which the
+        // JavaScript one recovers from as errors
+        "ts" => Some(tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()),
         "go" => Some(tree_sitter_go::LANGUAGE.into()),
+        "java" => Some(tree_sitter_java::LANGUAGE.into()),
+        "cs" => Some(tree_sitter_c_sharp::LANGUAGE.into()),
+        "rb" | "rake" => Some(tree_sitter_ruby::LANGUAGE.into()),
+        // The PHP grammar (not PHP_ONLY) also accepts inline HTML around `<?php` blocks
+        "php" | "phtml" | "inc" => Some(tree_sitter_php::LANGUAGE_PHP.into()),
+        "kt" | "kts" => Some(tree_sitter_kotlin_ng::LANGUAGE.into()),
+        "swift" => Some(tree_sitter_swift::LANGUAGE.into()),
+        "sh" | "bash" | "zsh" => Some(tree_sitter_bash::LANGUAGE.into()),
+        "lua" => Some(tree_sitter_lua::LANGUAGE.into()),
+        "zig" => Some(tree_sitter_zig::LANGUAGE.into()),
+        "hs" => Some(tree_sitter_haskell::LANGUAGE.into()),
+        "scala" | "sc" => Some(tree_sitter_scala::LANGUAGE.into()),
+        "ex" | "exs" => Some(tree_sitter_elixir::LANGUAGE.into()),
+        // Configuration files: keys are reported as properties
+        "json" => Some(tree_sitter_json::LANGUAGE.into()),
+        "yaml" | "yml" => Some(tree_sitter_yaml::LANGUAGE.into()),
+        "toml" => Some(tree_sitter_toml_ng::LANGUAGE.into()),
+        // API schemas; Thrift has no grammar and is scanned instead
+        "proto" => Some(tree_sitter_proto::LANGUAGE.into()),
+        "graphql" | "gql" => Some(tree_sitter_graphql::LANGUAGE.into()),
+        "sql" => Some(tree_sitter_sequel::LANGUAGE.into()),
+        // Infrastructure and build files
+        "tf" | "hcl" => Some(tree_sitter_hcl::LANGUAGE.into()),
+        "dockerfile" | "containerfile" => Some(tree_sitter_containerfile::LANGUAGE.into()),
+        "mk" | "mak" => Some(tree_sitter_make::LANGUAGE.into()),
+        // Markup; single-file components are HTML with embedded scripts
+        "html" | "htm" | "vue" | "svelte" => Some(tree_sitter_html::LANGUAGE.into()),
+        // Only the block structure (sections and code fences) is parsed
+        "md" | "markdown" => Some(tree_sitter_md::LANGUAGE.into()),
         _ => None,
     }
 }
+
+/// Guess whether a `.h` file is C++ rather than C from its content.
+pub fn is_cpp_header(source_code: &str) -> bool {
+    CPP_HEADER_MARKERS.is_match(source_code)
+}
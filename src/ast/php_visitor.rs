@@ -0,0 +1,205 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct PhpVisitor {
+    symbols: Vec<Symbol>,
+    /// Current namespace, e.g. `App\Models`.
+    namespace: Option<String>,
+    /// Enclosing class, interface, trait or enum (PHP types do not nest).
+    class: Option<String>,
+}
+
+impl PhpVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            namespace: None,
+            class: None,
+        }
+    }
+
+    /// Namespace members are qualified with `\`, class members with `::`.
+    fn scoped_symbol(&self, kind: SymbolKind, name: String, node: &Node) -> Symbol {
+        let (container, qualified_name) = match (&self.class, &self.namespace) {
+            (Some(class), _) => (Some(class.clone()), format!("{}::{}", class, name)),
+            (None, Some(namespace)) => (Some(namespace.clone()), format!("{}\\{}", namespace, name)),
+            (None, None) => (None, name.clone()),
+        };
+
+        Symbol {
+            container,
+            qualified_name: Some(qualified_name),
+            ..Symbol::new(kind, name, node)
+        }
+    }
+
+    fn extract_named(&self, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..self.scoped_symbol(kind, name, node)
+        })
+    }
+
+    fn extract_method(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let kind = match node.child_by_field_name("name").map(|n| n.utf8_text(source.as_bytes())) {
+            Some(Ok("__construct")) => SymbolKind::Constructor,
+            _ => SymbolKind::Method,
+        };
+        let mut symbols: Vec<Symbol> = self.extract_named(node, source, kind).into_iter().collect();
+
+        // Constructor property promotion: `__construct(private string $id)`
+        if kind == SymbolKind::Constructor
+            && let Some(parameters) = node.child_by_field_name("parameters")
+        {
+            let mut cursor = parameters.walk();
+            for parameter in parameters.named_children(&mut cursor) {
+                if parameter.kind() == "property_promotion_parameter"
+                    && let Some(symbol) = self.extract_variable(&parameter, &parameter, source, SymbolKind::Property)
+                {
+                    symbols.push(symbol);
+                }
+            }
+        }
+        symbols
+    }
+
+    /// Properties are reported as `$name`, matching how they are written.
+    fn extract_variable(&self, element: &Node, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let name_node = element.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..self.scoped_symbol(kind, name, node)
+        })
+    }
+
+    /// `public $a = 1, $b;` declares one property per element.
+    fn extract_properties(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .filter(|child| child.kind() == "property_element")
+            .filter_map(|element| self.extract_variable(&element, node, source, SymbolKind::Property))
+            .collect()
+    }
+
+    /// `const A = 1, B = 2;` declares one constant per element.
+    fn extract_constants(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .filter(|child| child.kind() == "const_element")
+            .filter_map(|element| {
+                let mut cursor = element.walk();
+                let name_node = element.named_children(&mut cursor).find(|c| c.kind() == "name")?;
+                let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+                Some(Symbol {
+                    name_range: Some(name_node.byte_range()),
+                    ..self.scoped_symbol(SymbolKind::Constant, name, node)
+                })
+            })
+            .collect()
+    }
+
+    fn type_kind(node_kind: &str) -> Option<SymbolKind> {
+        match node_kind {
+            "class_declaration" => Some(SymbolKind::Class),
+            "interface_declaration" => Some(SymbolKind::Interface),
+            "trait_declaration" => Some(SymbolKind::Trait),
+            "enum_declaration" => Some(SymbolKind::Enum),
+            _ => None,
+        }
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for PhpVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        if let Some(kind) = Self::type_kind(node.kind()) {
+            if let Some(symbol) = self.extract_named(node, source_code, kind) {
+                let qualified_name = symbol.qualified_name.clone();
+                self.symbols.push(symbol);
+                let outer = std::mem::replace(&mut self.class, qualified_name);
+                self.visit_children(node, source_code);
+                self.class = outer;
+            }
+            return;
+        }
+
+        match node.kind() {
+            "namespace_definition" => {
+                let name_node = node.child_by_field_name("name");
+                let Some(name) = name_node
+                    .and_then(|n| n.utf8_text(source_code.as_bytes()).ok())
+                    .map(|n| n.to_string())
+                else {
+                    // Global namespace block: `namespace { ... }`
+                    let outer = self.namespace.take();
+                    self.visit_children(node, source_code);
+                    self.namespace = outer;
+                    return;
+                };
+
+                let mut symbol = Symbol {
+                    name_range: name_node.map(|n| n.byte_range()),
+                    qualified_name: Some(name.clone()),
+                    ..Symbol::new(SymbolKind::Namespace, name.clone(), node)
+                };
+
+                if let Some(body) = node.child_by_field_name("body") {
+                    symbol.body_range = Some(body.byte_range());
+                    self.symbols.push(symbol);
+                    let outer = self.namespace.replace(name);
+                    self.visit_children(node, source_code);
+                    self.namespace = outer;
+                } else {
+                    // `namespace App;` applies to the rest of the file
+                    if let Some(program) = node.parent() {
+                        symbol.end_line = program.end_position().row + 1;
+                        symbol.full_range = node.start_byte()..program.end_byte();
+                    }
+                    self.symbols.push(symbol);
+                    self.namespace = Some(name);
+                }
+                return;
+            }
+            "function_definition" => {
+                if let Some(symbol) = self.extract_named(node, source_code, SymbolKind::Function) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "method_declaration" => {
+                let symbols = self.extract_method(node, source_code);
+                self.symbols.extend(symbols);
+            }
+            "property_declaration" => {
+                let symbols = self.extract_properties(node, source_code);
+                self.symbols.extend(symbols);
+                return;
+            }
+            "const_declaration" => {
+                let symbols = self.extract_constants(node, source_code);
+                self.symbols.extend(symbols);
+                return;
+            }
+            _ => {}
+        }
+
+        self.visit_children(node, source_code);
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,407 @@
+//! The C preprocessor as far as symbols are concerned: `#define` macros,
+//! and the `#if` branches that declarations live in. Given the macros
+//! defined for a build, branches the build leaves out can be skipped.
+
+use super::symbol::{Symbol, SymbolKind};
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+/// One branch of an `#if`, `#ifdef` or `#ifndef` chain.
+pub struct Branch<'a> {
+    /// Condition under which the branch is compiled, including the negated
+    /// conditions of the branches before it: `!defined(USE_GPU) && VERSION
+    /// == 1` for an `#elif`. `None` for the body of an include guard.
+    pub condition: Option<String>,
+    pub body: Vec<Node<'a>>,
+}
+
+/// Macros defined so far, when filtering branches by them.
+pub struct Preprocessor {
+    defines: Option<HashMap<String, String>>,
+}
+
+/// Parse `NAME` and `NAME=VALUE` definitions, as given to `-D`. A name
+/// without a value is defined as `1`.
+pub fn parse_defines(defines: &[String]) -> HashMap<String, String> {
+    defines.iter()
+        .map(|define| match define.split_once('=') {
+            Some((name, value)) => (name.trim().to_string(), value.trim().to_string()),
+            None => (define.trim().to_string(), "1".to_string()),
+        })
+        .collect()
+}
+
+/// `#define MAX 10` and `#define SQUARE(x) ((x) * (x))`, ending on the
+/// line of the directive rather than at its newline. Macros ignore scope, so
+/// the name is also the qualified name.
+pub fn macro_symbol(node: &Node, source: &str) -> Option<Symbol> {
+    let name_node = node.child_by_field_name("name")?;
+    let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+    let mut symbol = Symbol {
+        name_range: Some(name_node.byte_range()),
+        qualified_name: Some(name.clone()),
+        ..Symbol::new(SymbolKind::Macro, name, node)
+    };
+    let text = node.utf8_text(source.as_bytes()).ok()?;
+    symbol.full_range.end = node.start_byte() + text.trim_end().len();
+    symbol.end_line = symbol.start_line + text.trim_end().matches('\n').count();
+    Some(symbol)
+}
+
+/// Record that `symbols` are compiled under `condition`, within any
+/// conditions they already have from nested branches.
+pub fn set_condition(symbols: &mut [Symbol], condition: &str) {
+    for symbol in symbols {
+        symbol.condition = Some(match &symbol.condition {
+            Some(inner) => format!("{} && {}", condition, inner),
+            None => condition.to_string(),
+        });
+    }
+}
+
+fn negate(condition: &str) -> String {
+    if let Some(defined) = condition.strip_prefix('!')
+        && defined.starts_with("defined(")
+        && defined.find(')') == Some(defined.len() - 1)
+    {
+        return defined.to_string();
+    }
+    if condition.chars().all(|c| c.is_alphanumeric() || c == '_')
+        || (condition.starts_with("defined(") && condition.find(')') == Some(condition.len() - 1))
+    {
+        format!("!{}", condition)
+    } else {
+        format!("!({})", condition)
+    }
+}
+
+impl Preprocessor {
+    /// `defines` are the macros defined for the build; `None` keeps every
+    /// branch, as nothing is known about the build.
+    pub fn new(defines: Option<HashMap<String, String>>) -> Self {
+        Self { defines }
+    }
+
+    /// Track a `#define` met in a compiled branch, so that later conditions
+    /// see it.
+    pub fn define(&mut self, node: &Node, source: &str) {
+        let Some(defines) = &mut self.defines else {
+            return;
+        };
+        let Some(name) = node.child_by_field_name("name").and_then(|n| n.utf8_text(source.as_bytes()).ok()) else {
+            return;
+        };
+        // Function-like macros have no value of their own
+        let value = match node.kind() {
+            "preproc_def" => node.child_by_field_name("value")
+                .and_then(|v| v.utf8_text(source.as_bytes()).ok())
+                .unwrap_or_default()
+                .trim(),
+            _ => "",
+        };
+        defines.insert(name.to_string(), value.to_string());
+    }
+
+    /// Track an `#undef`, which the grammar parses as a generic directive.
+    pub fn undefine(&mut self, node: &Node, source: &str) {
+        let Some(defines) = &mut self.defines else {
+            return;
+        };
+        let directive = node.child_by_field_name("directive").and_then(|d| d.utf8_text(source.as_bytes()).ok());
+        if directive == Some("#undef")
+            && let Some(name) = node.child_by_field_name("argument").and_then(|a| a.utf8_text(source.as_bytes()).ok())
+        {
+            defines.remove(name.trim());
+        }
+    }
+
+    /// Condition of a single `#if`, `#elif` or `#ifdef` directive, or
+    /// `None` for `#else`.
+    fn directive_condition(node: &Node, source: &str) -> Option<String> {
+        if let Some(condition) = node.child_by_field_name("condition") {
+            let text = condition.utf8_text(source.as_bytes()).ok()?;
+            return Some(text.split_whitespace().collect::<Vec<_>>().join(" "));
+        }
+        let name = node.child_by_field_name("name")?.utf8_text(source.as_bytes()).ok()?;
+        let directive = node.child(0)?.kind();
+        if directive.ends_with("ndef") {
+            Some(format!("!defined({})", name))
+        } else {
+            Some(format!("defined({})", name))
+        }
+    }
+
+    /// Statements of a branch: the children of its directive other than the
+    /// condition and the branches after it.
+    fn branch_body<'a>(node: &Node<'a>) -> Vec<Node<'a>> {
+        let mut body = Vec::new();
+        for i in 0..node.child_count() {
+            let field = node.field_name_for_child(i as u32);
+            if matches!(field, Some("condition" | "name" | "alternative")) {
+                continue;
+            }
+            if let Some(child) = node.child(i).filter(|c| c.is_named()) {
+                body.push(child);
+            }
+        }
+        body
+    }
+
+    /// `#ifndef GUARD_H` followed by `#define GUARD_H`, wrapping a header.
+    fn is_include_guard(node: &Node, source: &str) -> bool {
+        if node.kind() != "preproc_ifdef"
+            || node.child(0).map(|c| c.kind()) != Some("#ifndef")
+            || node.child_by_field_name("alternative").is_some()
+        {
+            return false;
+        }
+        let name = node.child_by_field_name("name").and_then(|n| n.utf8_text(source.as_bytes()).ok());
+        let defined = Self::branch_body(node).into_iter()
+            .find(|child| child.kind() != "comment")
+            .filter(|child| child.kind() == "preproc_def")
+            .and_then(|def| def.child_by_field_name("name"))
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok());
+        name.is_some() && name == defined
+    }
+
+    /// The branches of the chain starting at `node` that may be compiled:
+    /// all of them without defines, and otherwise those whose condition is
+    /// not known to be false. Conditions that cannot be evaluated, such as
+    /// calls of function-like macros, keep their branch.
+    pub fn branches<'a>(&self, node: Node<'a>, source: &str) -> Vec<Branch<'a>> {
+        // The guard's own `#define` is no symbol of the header
+        if Self::is_include_guard(&node, source) {
+            let mut body = Self::branch_body(&node);
+            if let Some(guard) = body.iter().position(|child| child.kind() == "preproc_def") {
+                body.remove(guard);
+            }
+            return vec![Branch { condition: None, body }];
+        }
+
+        let mut branches = Vec::new();
+        let mut previous: Vec<String> = Vec::new();
+        let mut taken = false;
+        let mut directive = Some(node);
+        while let Some(node) = directive {
+            let condition = Self::directive_condition(&node, source);
+            let value = match (&self.defines, &condition) {
+                (None, _) => None,
+                _ if taken => Some(false),
+                (Some(_), None) => Some(true),
+                (Some(defines), Some(condition)) => evaluate(condition, defines),
+            };
+
+            if value != Some(false) {
+                let parts: Vec<String> = previous.iter()
+                    .map(|condition| negate(condition))
+                    .chain(condition.clone())
+                    .collect();
+                branches.push(Branch {
+                    condition: Some(parts.join(" && ")).filter(|c| !c.is_empty()),
+                    body: Self::branch_body(&node),
+                });
+            }
+            taken |= value == Some(true);
+            previous.extend(condition);
+            directive = node.child_by_field_name("alternative");
+        }
+        branches
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(i64),
+    Ident(String),
+    Op(&'static str),
+}
+
+const OPERATORS: [&str; 22] = [
+    "||", "&&", "==", "!=", "<=", ">=", "<<", ">>",
+    "|", "^", "&", "<", ">", "+", "-", "*", "/", "%", "!", "~", "(", ")",
+];
+
+fn tokenize(text: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = text.trim_start();
+    while !rest.is_empty() {
+        let c = rest.chars().next()?;
+        let len = if c.is_ascii_digit() {
+            let len = rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len());
+            let digits = rest[..len].trim_end_matches(['u', 'U', 'l', 'L']);
+            let number = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+                i64::from_str_radix(hex, 16).ok()?
+            } else if digits.len() > 1 && digits.starts_with('0') {
+                i64::from_str_radix(&digits[1..], 8).ok()?
+            } else {
+                digits.parse().ok()?
+            };
+            tokens.push(Token::Number(number));
+            len
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest.find(|c: char| !c.is_alphanumeric() && c != '_').unwrap_or(rest.len());
+            tokens.push(Token::Ident(rest[..len].to_string()));
+            len
+        } else {
+            let op = OPERATORS.iter().find(|op| rest.starts_with(**op))?;
+            tokens.push(Token::Op(op));
+            op.len()
+        };
+        rest = rest[len..].trim_start();
+    }
+    Some(tokens)
+}
+
+fn precedence(op: &str) -> Option<u8> {
+    Some(match op {
+        "||" => 1,
+        "&&" => 2,
+        "|" => 3,
+        "^" => 4,
+        "&" => 5,
+        "==" | "!=" => 6,
+        "<" | ">" | "<=" | ">=" => 7,
+        "<<" | ">>" => 8,
+        "+" | "-" => 9,
+        "*" | "/" | "%" => 10,
+        _ => return None,
+    })
+}
+
+/// Values are `None` when unknown; parse errors are `None` one level up.
+struct Evaluator<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    defines: &'a HashMap<String, String>,
+    depth: usize,
+}
+
+impl Evaluator<'_> {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn close(&mut self) -> Option<()> {
+        (self.next()? == Token::Op(")")).then_some(())
+    }
+
+    fn expression(&mut self, min_precedence: u8) -> Option<Option<i64>> {
+        let mut left = self.unary()?;
+        while let Some(Token::Op(op)) = self.peek().cloned()
+            && let Some(precedence) = precedence(op)
+            && precedence >= min_precedence
+        {
+            self.position += 1;
+            let right = self.expression(precedence + 1)?;
+            left = match (op, left, right) {
+                // Either side settles these, even when the other is unknown
+                ("||", Some(a), _) | ("||", _, Some(a)) if a != 0 => Some(1),
+                ("&&", Some(0), _) | ("&&", _, Some(0)) => Some(0),
+                ("||", Some(_), Some(_)) => Some(0),
+                ("&&", Some(_), Some(_)) => Some(1),
+                (_, Some(a), Some(b)) => match op {
+                    "|" => Some(a | b),
+                    "^" => Some(a ^ b),
+                    "&" => Some(a & b),
+                    "==" => Some((a == b) as i64),
+                    "!=" => Some((a != b) as i64),
+                    "<" => Some((a < b) as i64),
+                    ">" => Some((a > b) as i64),
+                    "<=" => Some((a <= b) as i64),
+                    ">=" => Some((a >= b) as i64),
+                    "<<" => a.checked_shl(b.try_into().ok()?),
+                    ">>" => a.checked_shr(b.try_into().ok()?),
+                    "+" => a.checked_add(b),
+                    "-" => a.checked_sub(b),
+                    "*" => a.checked_mul(b),
+                    "/" => a.checked_div(b),
+                    _ => a.checked_rem(b),
+                },
+                _ => None,
+            };
+        }
+        Some(left)
+    }
+
+    fn unary(&mut self) -> Option<Option<i64>> {
+        match self.next()? {
+            Token::Number(n) => Some(Some(n)),
+            Token::Op("(") => {
+                let value = self.expression(1)?;
+                self.close()?;
+                Some(value)
+            }
+            Token::Op("!") => Some(self.unary()?.map(|v| (v == 0) as i64)),
+            Token::Op("~") => Some(self.unary()?.map(|v| !v)),
+            Token::Op("-") => Some(self.unary()?.map(|v| v.wrapping_neg())),
+            Token::Op("+") => self.unary(),
+            Token::Ident(name) if name == "defined" => {
+                let parenthesized = self.peek() == Some(&Token::Op("("));
+                if parenthesized {
+                    self.position += 1;
+                }
+                let Token::Ident(name) = self.next()? else {
+                    return None;
+                };
+                if parenthesized {
+                    self.close()?;
+                }
+                Some(Some(self.defines.contains_key(&name) as i64))
+            }
+            Token::Ident(name) => {
+                // A call of a function-like macro cannot be expanded here
+                if self.peek() == Some(&Token::Op("(")) {
+                    let mut depth = 0;
+                    while let Some(token) = self.next() {
+                        match token {
+                            Token::Op("(") => depth += 1,
+                            Token::Op(")") => depth -= 1,
+                            _ => {}
+                        }
+                        if depth == 0 {
+                            return Some(None);
+                        }
+                    }
+                    return None;
+                }
+                Some(match self.defines.get(&name) {
+                    // Macros defined in terms of themselves are not expanded
+                    Some(value) if self.depth < 16 => evaluate_at(value, self.defines, self.depth + 1),
+                    Some(_) => None,
+                    None if name == "true" => Some(1),
+                    // Undefined names, `false` included, are 0
+                    None => Some(0),
+                })
+            }
+            Token::Op(_) => None,
+        }
+    }
+}
+
+fn evaluate_at(text: &str, defines: &HashMap<String, String>, depth: usize) -> Option<i64> {
+    let tokens = tokenize(text)?;
+    if tokens.is_empty() {
+        return None;
+    }
+    let mut evaluator = Evaluator { tokens, position: 0, defines, depth };
+    let value = evaluator.expression(1)?;
+    // Trailing tokens mean the expression was not understood
+    if evaluator.position != evaluator.tokens.len() {
+        return None;
+    }
+    value
+}
+
+/// Value of an `#if` condition given the defined macros, or `None` when it
+/// cannot be told: malformed, or using function-like macros or macros
+/// defined without a value.
+pub fn evaluate(condition: &str, defines: &HashMap<String, String>) -> Option<bool> {
+    evaluate_at(condition, defines, 0).map(|value| value != 0)
+}
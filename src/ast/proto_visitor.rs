@@ -0,0 +1,101 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct ProtoVisitor {
+    symbols: Vec<Symbol>,
+    /// Package followed by the names of the enclosing messages and services.
+    scope: Vec<String>,
+}
+
+impl ProtoVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+        }
+    }
+
+    /// Definitions name themselves through a wrapper node such as
+    /// `message_name` or `rpc_name`.
+    fn extract_named(&self, node: &Node, source: &str, name_kind: &str, kind: SymbolKind) -> Option<Symbol> {
+        let mut cursor = node.walk();
+        let name_node = node.children(&mut cursor).find(|c| c.kind() == name_kind)?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+        let container = visitor::scope_container(&self.scope, ".");
+
+        let mut cursor = node.walk();
+        let body = node.children(&mut cursor).find(|c| matches!(c.kind(), "message_body" | "enum_body"));
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: body.map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(kind, name, container, ".", node)
+        })
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for ProtoVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "package" => {
+                // Applies to every definition in the file, so the symbol
+                // spans to the end of the file.
+                if let Some(mut symbol) = self.extract_named(node, source_code, "full_ident", SymbolKind::Namespace) {
+                    if let Some(file) = node.parent() {
+                        symbol.end_line = file.end_position().row + 1;
+                        symbol.full_range = node.start_byte()..file.end_byte();
+                    }
+                    self.scope.push(symbol.name.clone());
+                    self.symbols.push(symbol);
+                }
+            }
+            "import" => {
+                if let Some(path) = node.child_by_field_name("path")
+                    && let Ok(text) = path.utf8_text(source_code.as_bytes())
+                {
+                    self.symbols.push(Symbol {
+                        name_range: Some(path.byte_range()),
+                        ..Symbol::new(SymbolKind::Import, text.trim_matches('"').to_string(), node)
+                    });
+                }
+            }
+            "message" | "service" => {
+                let (name_kind, kind) = if node.kind() == "message" {
+                    ("message_name", SymbolKind::Struct)
+                } else {
+                    ("service_name", SymbolKind::Interface)
+                };
+                if let Some(symbol) = self.extract_named(node, source_code, name_kind, kind) {
+                    self.scope.push(symbol.name.clone());
+                    self.symbols.push(symbol);
+                    self.visit_children(node, source_code);
+                    self.scope.pop();
+                }
+            }
+            "enum" => {
+                if let Some(symbol) = self.extract_named(node, source_code, "enum_name", SymbolKind::Enum) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "rpc" => {
+                if let Some(symbol) = self.extract_named(node, source_code, "rpc_name", SymbolKind::Method) {
+                    self.symbols.push(symbol);
+                }
+            }
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
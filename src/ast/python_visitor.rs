@@ -1,45 +1,393 @@
-use super::symbol::{Symbol, SymbolKind};
-use super::visitor::LanguageVisitor;
+use super::symbol::{Symbol, SymbolKind, Visibility};
+use super::visitor::{self, LanguageVisitor};
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::LazyLock;
 use tree_sitter::Node;
 
+/// `MAX_RETRIES`, `_DEFAULT_TIMEOUT`: names written as constants.
+static CONSTANT_NAME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^_*[A-Z][A-Z0-9_]*$").unwrap()
+});
+
 pub struct PythonVisitor {
     symbols: Vec<Symbol>,
+    /// Also report variables assigned inside functions.
+    locals: bool,
+    /// Variables already reported, by enclosing function (`None` for the
+    /// module) and name; only the first assignment of each is kept.
+    assigned: HashSet<(Option<String>, String)>,
+    /// Indices of the symbols defined at the module level, whose visibility
+    /// `__all__` decides when present.
+    module_level: Vec<usize>,
 }
 
 impl PythonVisitor {
     pub fn new() -> Self {
         Self {
             symbols: Vec::new(),
+            locals: false,
+            assigned: HashSet::new(),
+            module_level: Vec::new(),
         }
     }
 
+    pub fn with_locals(mut self, enabled: bool) -> Self {
+        self.locals = enabled;
+        self
+    }
+
+    /// Methods are contained in their class, `Order.pay`.
     fn extract_function(&self, node: &Node, source: &str) -> Option<Symbol> {
         let name_node = node.child_by_field_name("name")?;
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
-        
+        let class = Self::enclosing_classes(node, source);
+
         Some(Symbol {
-            kind: SymbolKind::Function,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
             body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            qualified_name: class.as_ref().map(|class| format!("{}.{}", class, name)),
+            container: class,
+            ..Symbol::new(SymbolKind::Function, name, node)
         })
     }
 
+    /// Class whose body a definition appears in directly.
+    fn enclosing_class<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+        let mut parent = node.parent()?;
+        if parent.kind() == "decorated_definition" {
+            parent = parent.parent()?;
+        }
+        parent.parent().filter(|p| parent.kind() == "block" && p.kind() == "class_definition")
+    }
+
+    /// Classes a definition is nested in, outermost first: `Order.Inner`.
+    fn enclosing_classes(node: &Node, source: &str) -> Option<String> {
+        let mut scope = Vec::new();
+        let mut current = *node;
+        while let Some(class) = Self::enclosing_class(&current) {
+            scope.push(class.child_by_field_name("name")?.utf8_text(source.as_bytes()).ok()?.to_string());
+            current = class;
+        }
+        scope.reverse();
+        visitor::scope_container(&scope, ".")
+    }
+
+    /// Classes are reported by what their bases make them: `Enum` (and its
+    /// variants) subclasses as enums, `Protocol`s as interfaces and
+    /// `TypedDict`s as structs.
+    fn class_kind(node: &Node, source: &str) -> SymbolKind {
+        let Some(superclasses) = node.child_by_field_name("superclasses") else {
+            return SymbolKind::Class;
+        };
+        let mut cursor = superclasses.walk();
+        for base in superclasses.named_children(&mut cursor) {
+            // `Protocol[T]` is a subscript of the base
+            let base = match base.kind() {
+                "subscript" => base.child_by_field_name("value").unwrap_or(base),
+                _ => base,
+            };
+            let Ok(text) = base.utf8_text(source.as_bytes()) else {
+                continue;
+            };
+            // `enum.Enum` and `typing.Protocol` count as well
+            match text.rsplit('.').next().unwrap_or(text) {
+                "Enum" | "IntEnum" | "StrEnum" | "Flag" | "IntFlag" => return SymbolKind::Enum,
+                "Protocol" => return SymbolKind::Interface,
+                "TypedDict" => return SymbolKind::Struct,
+                _ => {}
+            }
+        }
+        SymbolKind::Class
+    }
+
     fn extract_class(&self, node: &Node, source: &str) -> Option<Symbol> {
         let name_node = node.child_by_field_name("name")?;
         let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
-        
+        let class = Self::enclosing_classes(node, source);
+
         Some(Symbol {
-            kind: SymbolKind::Class,
-            name,
-            start_line: node.start_position().row + 1,
-            end_line: node.end_position().row + 1,
-            full_range: node.byte_range(),
             name_range: Some(name_node.byte_range()),
             body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            qualified_name: class.as_ref().map(|class| format!("{}.{}", class, name)),
+            container: class,
+            ..Symbol::new(Self::class_kind(node, source), name, node)
+        })
+    }
+
+    /// Names with a leading underscore are private by convention, except
+    /// dunder names such as `__init__`.
+    fn name_visibility(name: &str) -> Visibility {
+        let is_dunder = name.len() > 4 && name.starts_with("__") && name.ends_with("__");
+        if name.starts_with('_') && !is_dunder {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        }
+    }
+
+    fn member(kind: SymbolKind, name_node: Node, source: &str, node: &Node, container: &str) -> Option<Symbol> {
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            container: Some(container.to_string()),
+            qualified_name: Some(format!("{}.{}", container, name)),
+            visibility: Some(Self::name_visibility(&name)),
+            ..Symbol::new(kind, name, node)
+        })
+    }
+
+    /// Attributes declared in a class body: annotations (`id: int`) are
+    /// fields, and plain assignments in an enum are its members. Instance
+    /// attributes assigned in `__init__` follow as properties.
+    fn extract_members(&self, node: &Node, source: &str, class: &Symbol) -> Vec<Symbol> {
+        let mut members = Vec::new();
+        let Some(body) = node.child_by_field_name("body") else {
+            return members;
+        };
+        let container = class.qualified_name.as_deref().unwrap_or(&class.name);
+
+        let mut cursor = body.walk();
+        for statement in body.named_children(&mut cursor) {
+            match statement.kind() {
+                "expression_statement" => {
+                    let Some(assignment) = statement.named_child(0).filter(|n| n.kind() == "assignment") else {
+                        continue;
+                    };
+                    let Some(name_node) = assignment.child_by_field_name("left").filter(|n| n.kind() == "identifier") else {
+                        continue;
+                    };
+                    let kind = match assignment.child_by_field_name("type") {
+                        Some(annotation) => {
+                            let annotation = annotation.utf8_text(source.as_bytes()).unwrap_or_default();
+                            if annotation.rsplit('.').next() == Some("TypeAlias") {
+                                continue;
+                            }
+                            SymbolKind::Field
+                        }
+                        // `_ignore_` and friends configure the enum
+                        None if class.kind == SymbolKind::Enum
+                            && !name_node.utf8_text(source.as_bytes()).unwrap_or("_").starts_with('_') =>
+                        {
+                            SymbolKind::EnumMember
+                        }
+                        None => continue,
+                    };
+                    members.extend(Self::member(kind, name_node, source, &statement, container));
+                }
+                "function_definition" => {
+                    let is_init = statement.child_by_field_name("name")
+                        .and_then(|n| n.utf8_text(source.as_bytes()).ok()) == Some("__init__");
+                    if is_init {
+                        self.extract_instance_attributes(&statement, source, container, &mut members);
+                    }
+                }
+                _ => {}
+            }
+        }
+        members
+    }
+
+    /// `self.x = ...` assignments in `__init__`, each attribute once. The
+    /// receiver is whatever the first parameter is called.
+    fn extract_instance_attributes(&self, init: &Node, source: &str, container: &str, members: &mut Vec<Symbol>) {
+        let receiver = init.child_by_field_name("parameters")
+            .and_then(|p| p.named_child(0))
+            .filter(|p| p.kind() == "identifier")
+            .and_then(|p| p.utf8_text(source.as_bytes()).ok());
+        let (Some(receiver), Some(body)) = (receiver, init.child_by_field_name("body")) else {
+            return;
+        };
+
+        let mut attributes: Vec<Symbol> = Vec::new();
+        let mut stack = vec![body];
+        while let Some(node) = stack.pop() {
+            if node.kind() == "assignment"
+                && let Some(left) = node.child_by_field_name("left").filter(|n| n.kind() == "attribute")
+                && left.child_by_field_name("object").and_then(|n| n.utf8_text(source.as_bytes()).ok()) == Some(receiver)
+                && let Some(name_node) = left.child_by_field_name("attribute")
+            {
+                let statement = node.parent().filter(|p| p.kind() == "expression_statement").unwrap_or(node);
+                attributes.extend(Self::member(SymbolKind::Property, name_node, source, &statement, container));
+            }
+            // Nested functions and classes have receivers of their own
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                if !matches!(child.kind(), "function_definition" | "class_definition" | "lambda") {
+                    stack.push(child);
+                }
+            }
+        }
+
+        // Keep the first assignment of each attribute
+        attributes.sort_by_key(|a| a.full_range.start);
+        for attribute in attributes {
+            if !members.iter().any(|m| m.name == attribute.name) {
+                members.push(attribute);
+            }
+        }
+    }
+
+    /// `Vector: TypeAlias = list[float]`.
+    fn extract_annotated_alias(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let annotation = node.child_by_field_name("type")?.utf8_text(source.as_bytes()).ok()?;
+        if annotation.rsplit('.').next() != Some("TypeAlias") {
+            return None;
+        }
+        let name_node = node.child_by_field_name("left").filter(|n| n.kind() == "identifier")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("right").map(|n| n.byte_range()),
+            ..Symbol::new(SymbolKind::Type, name, node)
+        })
+    }
+
+    /// Decorators apply to the definition they precede, which then starts
+    /// at the first of them.
+    fn with_decorators(mut symbol: Symbol, node: &Node, source: &str) -> Symbol {
+        let Some(decorated) = node.parent().filter(|p| p.kind() == "decorated_definition") else {
+            return symbol;
+        };
+        let mut cursor = decorated.walk();
+        symbol.decorators = decorated.children(&mut cursor)
+            .filter(|c| c.kind() == "decorator")
+            .filter_map(|decorator| decorator.named_child(0)?.utf8_text(source.as_bytes()).ok())
+            .map(|text| text.to_string())
+            .collect();
+        symbol.start_line = decorated.start_position().row + 1;
+        symbol.full_range.start = decorated.start_byte();
+        symbol
+    }
+
+    /// Name of the function an assignment is made in, `Some(None)` at the
+    /// module level (including `if`/`try` blocks there), and `None` in a
+    /// class body, where assignments are class attributes.
+    fn enclosing_function(node: &Node, source: &str) -> Option<Option<String>> {
+        let mut parent = node.parent();
+        while let Some(ancestor) = parent {
+            match ancestor.kind() {
+                "function_definition" => {
+                    let name = ancestor.child_by_field_name("name")?.utf8_text(source.as_bytes()).ok()?;
+                    return Some(Some(name.to_string()));
+                }
+                "class_definition" | "lambda" => return None,
+                _ => parent = ancestor.parent(),
+            }
+        }
+        Some(None)
+    }
+
+    /// Identifiers bound by an assignment target, unpacking `a, (b, *c)`.
+    /// Attributes and subscripts (`self.x`, `d[k]`) bind no name.
+    fn target_names<'tree>(target: Node<'tree>, names: &mut Vec<Node<'tree>>) {
+        match target.kind() {
+            "identifier" => names.push(target),
+            "pattern_list" | "tuple_pattern" | "list_pattern" | "list_splat_pattern" => {
+                let mut cursor = target.walk();
+                for child in target.named_children(&mut cursor) {
+                    Self::target_names(child, names);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Module-level assignments, as constants when written in UPPER_CASE or
+    /// annotated `Final`, and otherwise as variables. Assignments inside
+    /// functions are only reported when locals are enabled.
+    fn extract_variables(&mut self, node: &Node, source: &str) -> Vec<Symbol> {
+        let Some(function) = Self::enclosing_function(node, source) else {
+            return Vec::new();
+        };
+        if function.is_some() && !self.locals {
+            return Vec::new();
+        }
+        let Some(left) = node.child_by_field_name("left") else {
+            return Vec::new();
+        };
+        let is_final = node.child_by_field_name("type")
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+            .is_some_and(|annotation| annotation.rsplit('.').next().is_some_and(|t| t.starts_with("Final")));
+
+        // Chained assignments (`x = y = 0`) span the whole statement
+        let mut statement = *node;
+        while let Some(parent) = statement.parent().filter(|p| matches!(p.kind(), "assignment" | "expression_statement")) {
+            statement = parent;
+        }
+
+        let mut names = Vec::new();
+        Self::target_names(left, &mut names);
+        let mut variables = Vec::new();
+        for name_node in names {
+            let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                continue;
+            };
+            if !self.assigned.insert((function.clone(), name.to_string())) {
+                continue;
+            }
+            let kind = if is_final || CONSTANT_NAME.is_match(name) { SymbolKind::Constant } else { SymbolKind::Variable };
+            let visibility = if function.is_some() { Visibility::Private } else { Self::name_visibility(name) };
+            variables.push(Symbol {
+                name_range: Some(name_node.byte_range()),
+                body_range: node.child_by_field_name("right").map(|n| n.byte_range()),
+                container: function.clone(),
+                qualified_name: Some(match &function {
+                    Some(function) => format!("{}.{}", function, name),
+                    None => name.to_string(),
+                }),
+                visibility: Some(visibility),
+                ..Symbol::new(kind, name.to_string(), &statement)
+            });
+        }
+        variables
+    }
+
+    /// Names listed in a module-level `__all__ = [...]` (or `+=`), each an
+    /// export contained in `__all__`.
+    fn extract_exports(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let is_all = node.child_by_field_name("left")
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok()) == Some("__all__");
+        let Some(list) = node.child_by_field_name("right").filter(|_| is_all) else {
+            return Vec::new();
+        };
+        if Self::enclosing_function(node, source) != Some(None) || !matches!(list.kind(), "list" | "tuple") {
+            return Vec::new();
+        }
+
+        let mut cursor = list.walk();
+        list.named_children(&mut cursor)
+            .filter(|item| item.kind() == "string")
+            .filter_map(|item| {
+                let mut cursor = item.walk();
+                let content = item.named_children(&mut cursor).find(|c| c.kind() == "string_content")?;
+                let name = content.utf8_text(source.as_bytes()).ok()?.to_string();
+                Some(Symbol {
+                    name_range: Some(content.byte_range()),
+                    container: Some("__all__".to_string()),
+                    qualified_name: Some(name.clone()),
+                    visibility: Some(Visibility::Exported),
+                    ..Symbol::new(SymbolKind::Export, name, &item)
+                })
+            })
+            .collect()
+    }
+
+    /// `type Point = tuple[float, float]` (Python 3.12), possibly generic
+    /// as in `type Pair[T] = tuple[T, T]`.
+    fn extract_type_alias(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let mut name_node = node.child_by_field_name("left")?;
+        while name_node.kind() != "identifier" {
+            name_node = name_node.named_child(0)?;
+        }
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("right").map(|n| n.byte_range()),
+            ..Symbol::new(SymbolKind::Type, name, node)
         })
     }
 }
@@ -47,13 +395,52 @@ impl PythonVisitor {
 impl LanguageVisitor for PythonVisitor {
     fn visit(&mut self, node: &Node, source_code: &str) {
         let maybe_symbol = match node.kind() {
-            "function_definition" => self.extract_function(node, source_code),
-            "class_definition" => self.extract_class(node, source_code),
+            "function_definition" => self.extract_function(node, source_code)
+                .map(|symbol| Self::with_decorators(symbol, node, source_code)),
+            "class_definition" => self.extract_class(node, source_code)
+                .map(|symbol| Self::with_decorators(symbol, node, source_code)),
+            "assignment" => match self.extract_annotated_alias(node, source_code) {
+                Some(alias) => Some(alias),
+                None => {
+                    let variables = self.extract_variables(node, source_code);
+                    for variable in variables {
+                        if variable.container.is_none() {
+                            self.module_level.push(self.symbols.len());
+                        }
+                        self.symbols.push(variable);
+                    }
+                    let exports = self.extract_exports(node, source_code);
+                    self.symbols.extend(exports);
+                    None
+                }
+            },
+            "augmented_assignment" => {
+                let exports = self.extract_exports(node, source_code);
+                self.symbols.extend(exports);
+                None
+            }
+            "type_alias_statement" => self.extract_type_alias(node, source_code),
             _ => None,
         };
 
-        if let Some(symbol) = maybe_symbol {
+        if let Some(mut symbol) = maybe_symbol {
+            // Definitions nested in functions are private to them
+            let function = Self::enclosing_function(node, source_code);
+            symbol.visibility = Some(match function {
+                Some(Some(_)) => Visibility::Private,
+                _ => Self::name_visibility(&symbol.name),
+            });
+            if function == Some(None) {
+                self.module_level.push(self.symbols.len());
+            }
+
+            // Attributes follow the class they belong to
+            let members = match node.kind() {
+                "class_definition" => self.extract_members(node, source_code, &symbol),
+                _ => Vec::new(),
+            };
             self.symbols.push(symbol);
+            self.symbols.extend(members);
         }
 
         // Recurse into children
@@ -64,7 +451,22 @@ impl LanguageVisitor for PythonVisitor {
         }
     }
 
-    fn get_symbols(self) -> Vec<Symbol> {
+    /// With an `__all__`, the module exports the names it lists and no
+    /// others.
+    fn get_symbols(mut self) -> Vec<Symbol> {
+        let exports: HashSet<String> = self.symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Export)
+            .map(|s| s.name.clone())
+            .collect();
+        if !exports.is_empty() {
+            for &index in &self.module_level {
+                let symbol = &mut self.symbols[index];
+                if Self::name_visibility(&symbol.name) == Visibility::Public && symbol.name.starts_with("__") {
+                    continue;
+                }
+                symbol.visibility = Some(if exports.contains(&symbol.name) { Visibility::Exported } else { Visibility::Private });
+            }
+        }
         self.symbols
     }
 }
@@ -0,0 +1,230 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+/// Calls that generate accessor methods on the receiving class.
+const ACCESSOR_MACROS: &[&str] = &[
+    "attr_accessor",
+    "attr_reader",
+    "attr_writer",
+    "has_many",
+    "has_one",
+    "belongs_to",
+    "has_and_belongs_to_many",
+];
+
+/// Calls that define (class-level) methods by name.
+const METHOD_MACROS: &[&str] = &["scope", "define_method"];
+
+/// RSpec/minitest blocks reported as tests.
+const TEST_BLOCKS: &[&str] = &["describe", "context", "it", "specify", "test"];
+
+pub struct RubyVisitor {
+    symbols: Vec<Symbol>,
+    /// Enclosing modules and classes, outermost first.
+    scope: Vec<String>,
+    /// Descriptions of the enclosing `describe`/`context` blocks.
+    test_scope: Vec<String>,
+    /// Inside `class << self`, where every `def` is a singleton method.
+    in_singleton_class: bool,
+}
+
+impl RubyVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+            test_scope: Vec::new(),
+            in_singleton_class: false,
+        }
+    }
+
+    fn container(&self) -> Option<String> {
+        visitor::scope_container(&self.scope, "::")
+    }
+
+    /// Builds a symbol qualified Ruby-style: `A::B` for constants, `A#m` for
+    /// instance methods and `A.m` for singleton methods.
+    fn scoped_symbol(&self, kind: SymbolKind, name: String, separator: &str, node: &Node) -> Symbol {
+        visitor::scoped_symbol(kind, name, self.container(), separator, node)
+    }
+
+    fn extract_namespace(&self, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..self.scoped_symbol(kind, name, "::", node)
+        })
+    }
+
+    fn extract_method(&self, node: &Node, source: &str, singleton: bool) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+        let separator = if singleton { "." } else { "#" };
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..self.scoped_symbol(SymbolKind::Method, name, separator, node)
+        })
+    }
+
+    fn extract_constant(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let left = node.child_by_field_name("left")?;
+        if left.kind() != "constant" {
+            return None;
+        }
+        let name = left.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(left.byte_range()),
+            body_range: node.child_by_field_name("right").map(|n| n.byte_range()),
+            ..self.scoped_symbol(SymbolKind::Constant, name, "::", node)
+        })
+    }
+
+    /// Plain-text value of a literal argument: `:name`, `"name"` or `Const`.
+    fn literal_text(node: &Node, source: &str) -> Option<String> {
+        let text = node.utf8_text(source.as_bytes()).ok()?;
+        match node.kind() {
+            "simple_symbol" => Some(text.trim_start_matches(':').to_string()),
+            "string" => Some(text.trim_matches(|c| c == '"' || c == '\'').to_string()),
+            "constant" | "scope_resolution" => Some(text.to_string()),
+            _ => None,
+        }
+    }
+
+    fn literal_arguments<'a>(node: &Node<'a>) -> Vec<Node<'a>> {
+        let Some(arguments) = node.child_by_field_name("arguments") else {
+            return Vec::new();
+        };
+        let mut cursor = arguments.walk();
+        arguments.named_children(&mut cursor).collect()
+    }
+
+    fn extract_macro_symbols(&self, node: &Node, source: &str, method: &str) -> Vec<Symbol> {
+        let arguments = Self::literal_arguments(node);
+
+        if ACCESSOR_MACROS.contains(&method) {
+            arguments.iter()
+                .filter_map(|arg| Self::literal_text(arg, source).map(|name| (arg, name)))
+                .map(|(arg, name)| Symbol {
+                    name_range: Some(arg.byte_range()),
+                    ..self.scoped_symbol(SymbolKind::Property, name, "#", node)
+                })
+                .collect()
+        } else if METHOD_MACROS.contains(&method) {
+            let singleton = method == "scope";
+            let separator = if singleton { "." } else { "#" };
+            arguments.first()
+                .and_then(|arg| Self::literal_text(arg, source).map(|name| (arg, name)))
+                .map(|(arg, name)| Symbol {
+                    name_range: Some(arg.byte_range()),
+                    ..self.scoped_symbol(SymbolKind::Method, name, separator, node)
+                })
+                .into_iter()
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// `describe Order do`, `it "pays" do`; qualified by the enclosing
+    /// descriptions the way RSpec builds full example names.
+    fn extract_test(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let arg = Self::literal_arguments(node).into_iter().next()?;
+        let name = Self::literal_text(&arg, source)?;
+        let container = visitor::scope_container(&self.test_scope, " ");
+
+        Some(Symbol {
+            name_range: Some(arg.byte_range()),
+            body_range: node.child_by_field_name("block").map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(SymbolKind::Test, name, container, " ", node)
+        })
+    }
+
+    fn visit_call(&mut self, node: &Node, source_code: &str) {
+        let method = node.child_by_field_name("method")
+            .and_then(|m| m.utf8_text(source_code.as_bytes()).ok())
+            .unwrap_or("");
+
+        if TEST_BLOCKS.contains(&method) && node.child_by_field_name("block").is_some() {
+            if let Some(symbol) = self.extract_test(node, source_code) {
+                self.test_scope.push(symbol.name.clone());
+                self.symbols.push(symbol);
+                self.visit_children(node, source_code);
+                self.test_scope.pop();
+                return;
+            }
+        } else if node.child_by_field_name("receiver").is_none() {
+            let symbols = self.extract_macro_symbols(node, source_code, method);
+            self.symbols.extend(symbols);
+        }
+
+        self.visit_children(node, source_code);
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for RubyVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "module" | "class" => {
+                let kind = if node.kind() == "module" { SymbolKind::Module } else { SymbolKind::Class };
+                if let Some(symbol) = self.extract_namespace(node, source_code, kind) {
+                    let in_singleton_class = std::mem::replace(&mut self.in_singleton_class, false);
+                    self.scope.push(symbol.name.clone());
+                    self.symbols.push(symbol);
+                    self.visit_children(node, source_code);
+                    self.scope.pop();
+                    self.in_singleton_class = in_singleton_class;
+                }
+                return;
+            }
+            "singleton_class" => {
+                let in_singleton_class = std::mem::replace(&mut self.in_singleton_class, true);
+                self.visit_children(node, source_code);
+                self.in_singleton_class = in_singleton_class;
+                return;
+            }
+            "method" => {
+                if let Some(symbol) = self.extract_method(node, source_code, self.in_singleton_class) {
+                    self.symbols.push(symbol);
+                }
+                return;
+            }
+            "singleton_method" => {
+                if let Some(symbol) = self.extract_method(node, source_code, true) {
+                    self.symbols.push(symbol);
+                }
+                return;
+            }
+            "assignment" => {
+                if let Some(symbol) = self.extract_constant(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "call" => {
+                self.visit_call(node, source_code);
+                return;
+            }
+            _ => {}
+        }
+
+        self.visit_children(node, source_code);
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,215 @@
+use super::symbol::{Symbol, SymbolKind, Visibility};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct RustVisitor {
+    symbols: Vec<Symbol>,
+    /// Enclosing modules, followed by the type of an enclosing `impl` or
+    /// trait.
+    scope: Vec<String>,
+    /// Whether functions are methods of an enclosing `impl` or trait.
+    in_impl: bool,
+    /// Whether items without a visibility modifier are public, as in traits
+    /// and trait implementations.
+    public_members: bool,
+}
+
+impl RustVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+            in_impl: false,
+            public_members: false,
+        }
+    }
+
+    fn container(&self) -> Option<String> {
+        visitor::scope_container(&self.scope, "::")
+    }
+
+    /// Outer attributes (`#[derive(Debug)]`, `#[test]`) are siblings that
+    /// precede the item, possibly with doc comments in between. The item
+    /// then starts at the first of them.
+    fn with_attributes(mut symbol: Symbol, node: &Node, source: &str) -> Symbol {
+        let mut attributes = Vec::new();
+        let mut sibling = node.prev_sibling();
+        while let Some(previous) = sibling {
+            match previous.kind() {
+                "attribute_item" => {
+                    if let Some(text) = previous.named_child(0).and_then(|n| n.utf8_text(source.as_bytes()).ok()) {
+                        attributes.push(text.to_string());
+                    }
+                    symbol.start_line = previous.start_position().row + 1;
+                    symbol.full_range.start = previous.start_byte();
+                }
+                "line_comment" | "block_comment" => {}
+                _ => break,
+            }
+            sibling = previous.prev_sibling();
+        }
+        attributes.reverse();
+        symbol.decorators = attributes;
+        symbol
+    }
+
+    /// `pub` items are public, and `pub(crate)`, `pub(super)` and
+    /// `pub(in path)` ones internal. Items without a modifier get `default`.
+    fn visibility(node: &Node, source: &str, default: Visibility) -> Visibility {
+        let mut cursor = node.walk();
+        let modifier = node.children(&mut cursor)
+            .find(|c| c.kind() == "visibility_modifier")
+            .and_then(|c| c.utf8_text(source.as_bytes()).ok());
+        match modifier {
+            Some("pub") => Visibility::Public,
+            Some("pub(self)") => Visibility::Private,
+            Some(_) => Visibility::Internal,
+            None => default,
+        }
+    }
+
+    fn scoped_symbol(&self, kind: SymbolKind, name_node: &Node, node: &Node, source: &str) -> Option<Symbol> {
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        let symbol = Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(kind, name, self.container(), "::", node)
+        };
+        let mut symbol = Self::with_attributes(symbol, node, source);
+
+        // `macro_rules!` macros are exported from the crate by attribute
+        let default = if self.public_members || symbol.decorators.iter().any(|a| a == "macro_export") {
+            Visibility::Public
+        } else {
+            Visibility::Private
+        };
+        symbol.visibility = Some(Self::visibility(node, source, default));
+        Some(symbol)
+    }
+
+    /// Functions marked `#[test]` (or `#[tokio::test]`) are tests, and those
+    /// in an `impl` or trait are methods.
+    fn function_kind(&self, symbol: &Symbol) -> SymbolKind {
+        if symbol.decorators.iter().any(|a| a == "test" || a.ends_with("::test")) {
+            SymbolKind::Test
+        } else if self.in_impl {
+            SymbolKind::Method
+        } else {
+            SymbolKind::Function
+        }
+    }
+
+    /// Named fields of a struct and variants of an enum, contained in the
+    /// type. Variants are as public as their enum.
+    fn extract_members(&self, node: &Node, source: &str, container: &str) -> Vec<Symbol> {
+        let Some(body) = node.child_by_field_name("body") else {
+            return Vec::new();
+        };
+        let mut cursor = body.walk();
+        body.named_children(&mut cursor)
+            .filter_map(|member| {
+                let (kind, default) = match member.kind() {
+                    "field_declaration" => (SymbolKind::Field, Visibility::Private),
+                    "enum_variant" => (SymbolKind::EnumMember, Visibility::Public),
+                    _ => return None,
+                };
+                let name_node = member.child_by_field_name("name")?;
+                let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+                let symbol = Symbol {
+                    name_range: Some(name_node.byte_range()),
+                    container: Some(container.to_string()),
+                    qualified_name: Some(format!("{}::{}", container, name)),
+                    visibility: Some(Self::visibility(&member, source, default)),
+                    ..Symbol::new(kind, name, &member)
+                };
+                Some(Self::with_attributes(symbol, &member, source))
+            })
+            .collect()
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for RustVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        let kind = match node.kind() {
+            "function_item" | "function_signature_item" => {
+                // Items nested in function bodies are not symbols
+                let symbol = node.child_by_field_name("name")
+                    .and_then(|name| self.scoped_symbol(SymbolKind::Function, &name, node, source_code));
+                if let Some(mut symbol) = symbol {
+                    symbol.kind = self.function_kind(&symbol);
+                    self.symbols.push(symbol);
+                }
+                return;
+            }
+            "mod_item" | "trait_item" | "impl_item" => {
+                // `impl<T> Display for Order<T>` is named after the type it
+                // is for, without type arguments
+                let (kind, name) = match node.kind() {
+                    "mod_item" => (SymbolKind::Module, node.child_by_field_name("name")),
+                    "trait_item" => (SymbolKind::Trait, node.child_by_field_name("name")),
+                    _ => {
+                        let mut name = node.child_by_field_name("type");
+                        while let Some(generic) = name.filter(|n| n.kind() == "generic_type") {
+                            name = generic.child_by_field_name("type");
+                        }
+                        (SymbolKind::Impl, name)
+                    }
+                };
+                let Some(mut symbol) = name.and_then(|name| self.scoped_symbol(kind, &name, node, source_code))
+                else {
+                    return;
+                };
+                // An `impl` block has no visibility of its own; its items do
+                if kind == SymbolKind::Impl {
+                    symbol.visibility = None;
+                }
+
+                let (was_in_impl, had_public_members) = (self.in_impl, self.public_members);
+                self.in_impl = kind != SymbolKind::Module;
+                self.public_members = kind == SymbolKind::Trait || node.child_by_field_name("trait").is_some();
+                self.scope.push(symbol.name.clone());
+                self.symbols.push(symbol);
+                self.visit_children(node, source_code);
+                self.scope.pop();
+                (self.in_impl, self.public_members) = (was_in_impl, had_public_members);
+                return;
+            }
+            "struct_item" => SymbolKind::Struct,
+            "enum_item" => SymbolKind::Enum,
+            "union_item" => SymbolKind::Union,
+            "type_item" => SymbolKind::Type,
+            "const_item" | "static_item" => SymbolKind::Constant,
+            "macro_definition" => SymbolKind::Macro,
+            _ => {
+                self.visit_children(node, source_code);
+                return;
+            }
+        };
+
+        if let Some(symbol) = node.child_by_field_name("name")
+            .and_then(|name| self.scoped_symbol(kind, &name, node, source_code))
+        {
+            let members = match kind {
+                SymbolKind::Struct | SymbolKind::Enum | SymbolKind::Union => {
+                    self.extract_members(node, source_code, symbol.qualified_name.as_deref().unwrap_or(&symbol.name))
+                }
+                _ => Vec::new(),
+            };
+            self.symbols.push(symbol);
+            self.symbols.extend(members);
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,210 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct ScalaVisitor {
+    symbols: Vec<Symbol>,
+    /// Package followed by the names of the enclosing objects, classes and traits.
+    scope: Vec<String>,
+    /// Number of enclosing template definitions, to tell methods from
+    /// top-level functions.
+    type_depth: usize,
+    /// Receiver type while inside an `extension (s: String)` block.
+    receiver: Option<String>,
+}
+
+impl ScalaVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+            type_depth: 0,
+            receiver: None,
+        }
+    }
+
+    fn container(&self) -> Option<String> {
+        visitor::scope_container(&self.scope, ".")
+    }
+
+    fn has_keyword(node: &Node, keyword: &str) -> bool {
+        let mut cursor = node.walk();
+        node.children(&mut cursor).any(|c| c.kind() == keyword)
+    }
+
+    /// `case class` is reported as a record, like Java and C# records.
+    fn extract_template(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let kind = match node.kind() {
+            "class_definition" if Self::has_keyword(node, "case") => SymbolKind::Record,
+            "trait_definition" => SymbolKind::Trait,
+            "enum_definition" => SymbolKind::Enum,
+            _ => SymbolKind::Class,
+        };
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(kind, name, self.container(), ".", node)
+        })
+    }
+
+    fn extract_function(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+        let kind = if self.type_depth > 0 {
+            SymbolKind::Method
+        } else {
+            SymbolKind::Function
+        };
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(kind, name, self.receiver.clone().or_else(|| self.container()), ".", node)
+        })
+    }
+
+    /// `val`/`var` definitions and abstract declarations. Members of a
+    /// template are properties; destructuring patterns are skipped.
+    fn extract_value(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("pattern")
+            .or_else(|| node.child_by_field_name("name"))
+            .filter(|n| n.kind() == "identifier")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+        let kind = if self.type_depth > 0 {
+            SymbolKind::Property
+        } else {
+            SymbolKind::Variable
+        };
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("value").map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(kind, name, self.container(), ".", node)
+        })
+    }
+
+    /// Primary constructor parameters declared with `val`/`var`.
+    fn extract_class_parameter(&self, node: &Node, source: &str) -> Option<Symbol> {
+        if !Self::has_keyword(node, "val") && !Self::has_keyword(node, "var") {
+            return None;
+        }
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..visitor::scoped_symbol(SymbolKind::Property, name, self.container(), ".", node)
+        })
+    }
+
+    /// Given instances are named after their identifier or, when anonymous
+    /// (`given Ordering[String] = ...`), after the provided type.
+    fn extract_given(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")
+            .or_else(|| node.child_by_field_name("return_type"))?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(SymbolKind::Impl, name, self.container(), ".", node)
+        })
+    }
+
+    fn extract_type_alias(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("name")?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..visitor::scoped_symbol(SymbolKind::Type, name, self.container(), ".", node)
+        })
+    }
+
+    /// The receiver of `extension (s: List[T])`, without type arguments.
+    fn receiver_type(node: &Node, source: &str) -> Option<String> {
+        let parameters = node.child_by_field_name("parameters")?;
+        let mut cursor = parameters.walk();
+        let parameter = parameters.named_children(&mut cursor).find(|c| c.kind() == "parameter")?;
+        let text = parameter.child_by_field_name("type")?.utf8_text(source.as_bytes()).ok()?;
+        Some(text.split('[').next().unwrap_or(text).trim().to_string())
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for ScalaVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "package_clause" => {
+                let Some(package) = node.child_by_field_name("name")
+                    .and_then(|n| n.utf8_text(source_code.as_bytes()).ok())
+                else {
+                    return;
+                };
+                // `package a.b` followed by `package c` nests for the rest of
+                // the file; a block `package inner { ... }` only scopes its body.
+                self.scope.push(package.to_string());
+                if node.child_by_field_name("body").is_some() {
+                    self.visit_children(node, source_code);
+                    self.scope.pop();
+                }
+            }
+            "object_definition" | "class_definition" | "trait_definition" | "enum_definition" => {
+                if let Some(symbol) = self.extract_template(node, source_code) {
+                    self.scope.push(symbol.name.clone());
+                    self.type_depth += 1;
+                    self.symbols.push(symbol);
+                    self.visit_children(node, source_code);
+                    self.type_depth -= 1;
+                    self.scope.pop();
+                }
+            }
+            "function_definition" | "function_declaration" => {
+                // Local declarations inside function bodies are not symbols
+                if let Some(symbol) = self.extract_function(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "val_definition" | "var_definition" | "val_declaration" | "var_declaration" => {
+                if let Some(symbol) = self.extract_value(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "class_parameter" => {
+                if let Some(symbol) = self.extract_class_parameter(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "given_definition" => {
+                if let Some(symbol) = self.extract_given(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "type_definition" => {
+                if let Some(symbol) = self.extract_type_alias(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "extension_definition" => {
+                self.receiver = Self::receiver_type(node, source_code);
+                self.visit_children(node, source_code);
+                self.receiver = None;
+            }
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
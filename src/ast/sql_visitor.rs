@@ -0,0 +1,186 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::LanguageVisitor;
+use regex::Regex;
+use std::sync::LazyLock;
+use tree_sitter::Node;
+
+/// `CREATE PROCEDURE` is not part of the grammar and parses as an error;
+/// the procedure is recovered from the text of the error node.
+static CREATE_PROCEDURE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)^CREATE\s+(?:OR\s+REPLACE\s+)?PROCEDURE\s+([\w."`\[\]]+)"#).unwrap()
+});
+
+pub struct SqlVisitor {
+    symbols: Vec<Symbol>,
+}
+
+impl SqlVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+        }
+    }
+
+    /// Identifiers may be quoted: `"orders"`, `` `orders` `` or `[orders]`.
+    fn unquote(text: &str) -> String {
+        text.split('.')
+            .map(|part| part.trim_matches(|c| matches!(c, '"' | '`' | '[' | ']')))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    fn child_of_kind<'tree>(node: &Node<'tree>, kind: &str) -> Option<Node<'tree>> {
+        let mut cursor = node.walk();
+        node.children(&mut cursor).find(|c| c.kind() == kind)
+    }
+
+    /// The object a statement creates or alters, as its `(schema.)name`,
+    /// with the node holding the name.
+    fn object_name<'tree>(node: &Node<'tree>, source: &str) -> Option<(String, Node<'tree>)> {
+        let reference = Self::child_of_kind(node, "object_reference")?;
+        let name_node = reference.child_by_field_name("name")?;
+        let name = reference.utf8_text(source.as_bytes()).ok()?;
+        Some((Self::unquote(name), name_node))
+    }
+
+    /// Split `schema.name` into its container and bare name.
+    fn scoped_symbol(kind: SymbolKind, qualified_name: String, node: &Node) -> Symbol {
+        let (container, name) = match qualified_name.rsplit_once('.') {
+            Some((container, name)) => (Some(container.to_string()), name.to_string()),
+            None => (None, qualified_name.clone()),
+        };
+
+        Symbol {
+            container,
+            qualified_name: Some(qualified_name),
+            ..Symbol::new(kind, name, node)
+        }
+    }
+
+    fn extract_object(&self, node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+        let (qualified_name, name_node) = Self::object_name(node, source)?;
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..Self::scoped_symbol(kind, qualified_name, node)
+        })
+    }
+
+    /// Columns defined in `node` (a table's column list or an `ADD COLUMN`),
+    /// as fields of `table`.
+    fn extract_columns(&self, node: &Node, source: &str, table: &str) -> Vec<Symbol> {
+        let mut columns = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "column_definition" => {
+                    let Some(name_node) = child.child_by_field_name("name") else {
+                        continue;
+                    };
+                    let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+                        continue;
+                    };
+                    columns.push(Symbol {
+                        name_range: Some(name_node.byte_range()),
+                        ..Self::scoped_symbol(SymbolKind::Field, format!("{}.{}", table, Self::unquote(name)), &child)
+                    });
+                }
+                "add_column" | "column_definitions" => columns.extend(self.extract_columns(&child, source, table)),
+                _ => {}
+            }
+        }
+        columns
+    }
+
+    fn visit_table(&mut self, node: &Node, source: &str, altered: bool) {
+        let Some(mut table) = self.extract_object(node, source, SymbolKind::Table) else {
+            return;
+        };
+        // `ALTER TABLE` changes a table defined elsewhere
+        table.alteration = altered;
+        let qualified_name = table.qualified_name.clone().unwrap_or_default();
+        self.symbols.push(table);
+
+        let columns = self.extract_columns(node, source, &qualified_name);
+        self.symbols.extend(columns);
+    }
+
+    /// Indexes are listed under the table they are defined on. Unnamed
+    /// indexes (`CREATE INDEX ON orders (...)`) are skipped.
+    fn extract_index(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let name_node = node.child_by_field_name("column")?;
+        let name = Self::unquote(name_node.utf8_text(source.as_bytes()).ok()?);
+        let (table, _) = Self::object_name(node, source)?;
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            container: Some(table.clone()),
+            qualified_name: Some(format!("{}.{}", table, name)),
+            ..Symbol::new(SymbolKind::Index, name, node)
+        })
+    }
+
+    /// A `CREATE PROCEDURE` error node, spanning up to the `;` that ends the
+    /// statement.
+    fn extract_procedure(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let text = &source[node.start_byte()..];
+        let captures = CREATE_PROCEDURE.captures(text)?;
+        let name = captures.get(1)?;
+        let name_range = node.start_byte() + name.start()..node.start_byte() + name.end();
+
+        let mut end = *node;
+        while let Some(next) = end.next_sibling() {
+            end = next;
+            if next.kind() == ";" {
+                break;
+            }
+        }
+
+        let mut symbol = Self::scoped_symbol(SymbolKind::Function, Self::unquote(name.as_str()), node);
+        symbol.name_range = Some(name_range);
+        symbol.end_line = end.end_position().row + 1;
+        symbol.full_range.end = end.end_byte();
+        Some(symbol)
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for SqlVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "create_table" => self.visit_table(node, source_code, false),
+            "alter_table" => self.visit_table(node, source_code, true),
+            "create_view" | "create_materialized_view" => {
+                if let Some(symbol) = self.extract_object(node, source_code, SymbolKind::View) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "create_index" => {
+                if let Some(symbol) = self.extract_index(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "create_function" => {
+                if let Some(symbol) = self.extract_object(node, source_code, SymbolKind::Function) {
+                    self.symbols.push(symbol);
+                }
+            }
+            "ERROR" => match self.extract_procedure(node, source_code) {
+                Some(symbol) => self.symbols.push(symbol),
+                None => self.visit_children(node, source_code),
+            },
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,328 @@
+//! Stylesheets are scanned rather than parsed. SCSS and LESS have no
+//! tree-sitter grammar among our dependencies, and CSS goes through the
+//! same scanner so that all three report nesting, variables and at-rules
+//! alike. They share the same shape, statements ending in `;` and preludes
+//! followed by `{ }` blocks, which is all the scanner needs. It works on
+//! bytes, only slicing the source at the ASCII punctuation it stops on.
+
+use super::symbol::{Symbol, SymbolKind};
+use regex::Regex;
+use std::ops::Range;
+use std::sync::LazyLock;
+
+/// `$name: value` (SCSS), `@name: value` (LESS) or `--name: value`.
+static VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([$@][\w-]+|--[\w-]+)\s*:").unwrap()
+});
+
+/// `@mixin name(...)`, `@function name(...)` and `@keyframes name`.
+static NAMED_AT_RULE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^@(mixin|function|(?:-\w+-)?keyframes)\s+([\w-]+)").unwrap()
+});
+
+/// LESS mixins are rules whose selector takes parameters, `.bordered(@width) {`.
+static LESS_MIXIN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[.#]([\w-]+)\s*\(").unwrap()
+});
+
+#[derive(Clone, Default)]
+struct Scope {
+    /// Selector or at-rule of the enclosing block.
+    container: Option<String>,
+    /// Enclosing selector, with parents resolved, that nested rules extend.
+    selector: Option<String>,
+    top_level: bool,
+}
+
+struct Scanner<'a> {
+    source: &'a str,
+    /// Whether `//` starts a comment (SCSS and LESS, but not CSS).
+    line_comments: bool,
+    symbols: Vec<Symbol>,
+}
+
+/// Collapse whitespace, so multi-line selector lists read as one line.
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Split a selector list on its top-level commas.
+fn split_selectors(text: &str) -> Vec<&str> {
+    let mut selectors = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                selectors.push(text[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    selectors.push(text[start..].trim());
+    selectors
+}
+
+/// Full selector of a nested rule: `&:hover` in `.button` is
+/// `.button:hover` and `.icon` is `.button .icon`.
+fn resolve(parent: Option<&str>, selector: &str) -> String {
+    let Some(parent) = parent else {
+        return selector.to_string();
+    };
+    let mut resolved = Vec::new();
+    for parent in split_selectors(parent) {
+        for child in split_selectors(selector) {
+            if child.contains('&') {
+                resolved.push(child.replace('&', parent));
+            } else {
+                resolved.push(format!("{} {}", parent, child));
+            }
+        }
+    }
+    resolved.join(", ")
+}
+
+impl<'a> Scanner<'a> {
+    fn starts_comment(&self, i: usize) -> bool {
+        let rest = &self.source.as_bytes()[i..];
+        if rest.starts_with(b"/*") {
+            return true;
+        }
+        // `//` inside a value (`url(http://...)`) is not a comment; those sit
+        // in parentheses, which `prelude_end` skips as a whole
+        self.line_comments && rest.starts_with(b"//")
+    }
+
+    fn skip_comment(&self, i: usize) -> usize {
+        let bytes = self.source.as_bytes();
+        if bytes[i..].starts_with(b"/*") {
+            bytes[i + 2..].windows(2).position(|w| w == b"*/").map_or(bytes.len(), |end| i + 2 + end + 2)
+        } else {
+            bytes[i..].iter().position(|&b| b == b'\n').map_or(bytes.len(), |end| i + end)
+        }
+    }
+
+    fn skip_trivia(&self, mut i: usize) -> usize {
+        let bytes = self.source.as_bytes();
+        while i < bytes.len() {
+            if bytes[i].is_ascii_whitespace() {
+                i += 1;
+            } else if self.starts_comment(i) {
+                i = self.skip_comment(i);
+            } else {
+                break;
+            }
+        }
+        i
+    }
+
+    /// End of the prelude starting at `i`: the index of the first `;`, `{`
+    /// or `}` outside strings, parentheses and `#{}` interpolation.
+    fn prelude_end(&self, mut i: usize) -> usize {
+        let bytes = self.source.as_bytes();
+        let mut depth = 0;
+        while i < bytes.len() {
+            let c = bytes[i];
+            match c {
+                b'"' | b'\'' => {
+                    i += 1;
+                    while i < bytes.len() && bytes[i] != c {
+                        i += if bytes[i] == b'\\' { 2 } else { 1 };
+                    }
+                }
+                b'#' if bytes.get(i + 1) == Some(&b'{') => {
+                    i = bytes[i..].iter().position(|&b| b == b'}').map_or(bytes.len(), |end| i + end);
+                }
+                b'(' => depth += 1,
+                b')' => depth -= 1,
+                b';' | b'{' | b'}' if depth <= 0 => return i,
+                _ if depth <= 0 && self.starts_comment(i) => {
+                    i = self.skip_comment(i);
+                    continue;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        bytes.len()
+    }
+
+    /// Skip the block whose `{` precedes `i`, returning the index after its `}`.
+    fn skip_block(&self, mut i: usize) -> usize {
+        let bytes = self.source.as_bytes();
+        loop {
+            i = self.skip_trivia(i);
+            if i >= bytes.len() {
+                return bytes.len();
+            }
+            if bytes[i] == b'}' {
+                return i + 1;
+            }
+            let end = self.prelude_end(i);
+            match bytes.get(end) {
+                Some(b'{') => i = self.skip_block(end + 1),
+                Some(b';') => i = end + 1,
+                Some(_) => i = end,
+                None => return bytes.len(),
+            }
+        }
+    }
+
+    fn symbol(&self, kind: SymbolKind, name: String, name_range: Range<usize>, range: Range<usize>, scope: &Scope) -> Symbol {
+        Symbol {
+            name_range: Some(name_range),
+            container: scope.container.clone(),
+            qualified_name: Some(name.clone()),
+            ..Symbol::from_span(kind, name, self.source, range)
+        }
+    }
+
+    /// Variables are reported at the top level, and custom properties
+    /// (`--brand: blue`) in any rule.
+    fn scan_statement(&mut self, prelude: Range<usize>, end: usize, scope: &Scope) {
+        let text = &self.source[prelude.clone()];
+        let Some(captures) = VARIABLE.captures(text) else {
+            return;
+        };
+        let name = captures.get(1).unwrap();
+        if !scope.top_level && !name.as_str().starts_with("--") {
+            return;
+        }
+
+        let name_range = prelude.start + name.start()..prelude.start + name.end();
+        let mut symbol = self.symbol(SymbolKind::Variable, name.as_str().to_string(), name_range, prelude.start..end, scope);
+        symbol.qualified_name = Some(match &scope.selector {
+            Some(selector) => format!("{} {}", selector, name.as_str()),
+            None => name.as_str().to_string(),
+        });
+        self.symbols.push(symbol);
+    }
+
+    /// A rule or at-rule whose block starts after `open`, returning the
+    /// index after the block.
+    fn scan_rule(&mut self, prelude: Range<usize>, open: usize, scope: &Scope) -> usize {
+        let text = self.source[prelude.clone()].trim_end();
+        let name_range = prelude.start..prelude.start + text.len();
+
+        if let Some(captures) = NAMED_AT_RULE.captures(text) {
+            let kind = match &captures[1] {
+                "mixin" => SymbolKind::Macro,
+                "function" => SymbolKind::Function,
+                _ => SymbolKind::Class,
+            };
+            let name = captures.get(2).unwrap();
+            let end = self.skip_block(open + 1);
+            let name_range = prelude.start + name.start()..prelude.start + name.end();
+            let mut symbol = self.symbol(kind, name.as_str().to_string(), name_range, prelude.start..end, scope);
+            symbol.body_range = Some(open..end);
+            self.symbols.push(symbol);
+            return end;
+        }
+        if let Some(captures) = LESS_MIXIN.captures(text) {
+            let name = captures.get(1).unwrap();
+            let end = self.skip_block(open + 1);
+            let name_range = prelude.start + name.start()..prelude.start + name.end();
+            let mut symbol = self.symbol(SymbolKind::Macro, name.as_str().to_string(), name_range, prelude.start..end, scope);
+            symbol.body_range = Some(open..end);
+            self.symbols.push(symbol);
+            return end;
+        }
+
+        let (header, inner) = if text.starts_with('@') {
+            let keyword = text.split(|c: char| c.is_whitespace() || c == '(').next().unwrap_or(text);
+            match keyword {
+                // Conditional groups keep the selector they are nested in
+                "@media" | "@supports" | "@container" | "@layer" | "@document" => {
+                    let name = normalize(text);
+                    let inner = Scope {
+                        container: Some(name.clone()),
+                        selector: scope.selector.clone(),
+                        top_level: false,
+                    };
+                    (Some((SymbolKind::Module, name.clone(), name)), inner)
+                }
+                // `@font-face`, `@page`, and SCSS control flow (`@if`, `@each`)
+                _ => (None, Scope { top_level: false, ..scope.clone() }),
+            }
+        } else {
+            let name = normalize(text);
+            let selector = resolve(scope.selector.as_deref(), &name);
+            let inner = Scope {
+                container: Some(selector.clone()),
+                selector: Some(selector.clone()),
+                top_level: false,
+            };
+            (Some((SymbolKind::Selector, name, selector)), inner)
+        };
+
+        // Nested rules are reported after their parent, which only learns
+        // where it ends once they have been scanned
+        let Some((kind, name, qualified_name)) = header else {
+            return self.scan_block(open + 1, &inner);
+        };
+        let index = self.symbols.len();
+        let mut symbol = self.symbol(kind, name, name_range, prelude.start..open, scope);
+        symbol.qualified_name = Some(qualified_name);
+        self.symbols.push(symbol);
+
+        let end = self.scan_block(open + 1, &inner);
+        let symbol = &mut self.symbols[index];
+        symbol.end_line = self.source[..end].matches('\n').count() + 1;
+        symbol.full_range.end = end;
+        symbol.body_range = Some(open..end);
+        end
+    }
+
+    /// Scan statements and rules from `i` up to the `}` closing the block
+    /// (or the end of the file), returning the index after it.
+    fn scan_block(&mut self, mut i: usize, scope: &Scope) -> usize {
+        let len = self.source.len();
+        loop {
+            i = self.skip_trivia(i);
+            if i >= len {
+                return len;
+            }
+            if self.source.as_bytes()[i] == b'}' {
+                // A stray `}` at the top level closes nothing
+                if scope.top_level {
+                    i += 1;
+                    continue;
+                }
+                return i + 1;
+            }
+            let end = self.prelude_end(i);
+            match self.source.as_bytes().get(end) {
+                Some(b'{') => i = self.scan_rule(i..end, end, scope),
+                Some(b';') => {
+                    self.scan_statement(i..end, end + 1, scope);
+                    i = end + 1;
+                }
+                // The last declaration of a block may omit its `;`
+                Some(_) => {
+                    self.scan_statement(i..end, end, scope);
+                    i = end;
+                }
+                None => return len,
+            }
+        }
+    }
+}
+
+/// Rules (by selector), conditional at-rules such as `@media`, mixins,
+/// functions, keyframes and variables of a CSS, SCSS or LESS stylesheet.
+pub fn scan(source: &str, line_comments: bool) -> Vec<Symbol> {
+    let mut scanner = Scanner {
+        source,
+        line_comments,
+        symbols: Vec::new(),
+    };
+    let scope = Scope {
+        top_level: true,
+        ..Scope::default()
+    };
+    scanner.scan_block(0, &scope);
+    scanner.symbols
+}
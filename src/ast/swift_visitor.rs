@@ -0,0 +1,133 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct SwiftVisitor {
+    symbols: Vec<Symbol>,
+    /// Enclosing types; an `extension` contributes the extended type.
+    scope: Vec<String>,
+}
+
+impl SwiftVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+        }
+    }
+
+    fn scoped_symbol(&self, kind: SymbolKind, name_node: &Node, node: &Node, source: &str) -> Option<Symbol> {
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+        let container = visitor::scope_container(&self.scope, ".");
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: node.child_by_field_name("body").map(|n| n.byte_range()),
+            ..visitor::scoped_symbol(kind, name, container, ".", node)
+        })
+    }
+
+    /// `class`, `struct`, `enum`, `actor` and `extension` all parse as
+    /// `class_declaration`, told apart by their `declaration_kind`.
+    fn declaration_kind<'a>(node: &Node, source: &'a str) -> Option<&'a str> {
+        node.child_by_field_name("declaration_kind")?.utf8_text(source.as_bytes()).ok()
+    }
+
+    /// Name of the type an `extension` applies to, without generic arguments.
+    fn extended_type(node: &Node, source: &str) -> Option<String> {
+        let text = node.child_by_field_name("name")?.utf8_text(source.as_bytes()).ok()?;
+        Some(text.split('<').next().unwrap_or(text).trim().to_string())
+    }
+
+    /// `let a = 1, b = 2` binds one property per pattern.
+    fn extract_properties(&self, node: &Node, source: &str) -> Vec<Symbol> {
+        let mut cursor = node.walk();
+        node.children_by_field_name("name", &mut cursor)
+            .filter_map(|pattern| {
+                let name_node = pattern.child_by_field_name("bound_identifier")?;
+                self.scoped_symbol(SymbolKind::Property, &name_node, node, source)
+            })
+            .collect()
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for SwiftVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "class_declaration" => {
+                if Self::declaration_kind(node, source_code) == Some("extension") {
+                    if let Some(extended) = Self::extended_type(node, source_code) {
+                        self.scope.push(extended);
+                        self.visit_children(node, source_code);
+                        self.scope.pop();
+                    }
+                    return;
+                }
+
+                let kind = match Self::declaration_kind(node, source_code) {
+                    Some("struct") => SymbolKind::Struct,
+                    Some("enum") => SymbolKind::Enum,
+                    _ => SymbolKind::Class,
+                };
+                if let Some(name_node) = node.child_by_field_name("name")
+                    && let Some(symbol) = self.scoped_symbol(kind, &name_node, node, source_code)
+                {
+                    self.scope.push(symbol.name.clone());
+                    self.symbols.push(symbol);
+                    self.visit_children(node, source_code);
+                    self.scope.pop();
+                }
+            }
+            "protocol_declaration" => {
+                if let Some(name_node) = node.child_by_field_name("name")
+                    && let Some(symbol) = self.scoped_symbol(SymbolKind::Interface, &name_node, node, source_code)
+                {
+                    self.scope.push(symbol.name.clone());
+                    self.symbols.push(symbol);
+                    self.visit_children(node, source_code);
+                    self.scope.pop();
+                }
+            }
+            "function_declaration" | "protocol_function_declaration" => {
+                // Local declarations inside function bodies are not symbols
+                let kind = if self.scope.is_empty() { SymbolKind::Function } else { SymbolKind::Method };
+                if let Some(name_node) = node.child_by_field_name("name")
+                    && let Some(symbol) = self.scoped_symbol(kind, &name_node, node, source_code)
+                {
+                    self.symbols.push(symbol);
+                }
+            }
+            "init_declaration" => {
+                if let Some(name_node) = node.child_by_field_name("name")
+                    && let Some(symbol) = self.scoped_symbol(SymbolKind::Constructor, &name_node, node, source_code)
+                {
+                    self.symbols.push(symbol);
+                }
+            }
+            "property_declaration" | "protocol_property_declaration" => {
+                let symbols = self.extract_properties(node, source_code);
+                self.symbols.extend(symbols);
+            }
+            "typealias_declaration" => {
+                if let Some(name_node) = node.child_by_field_name("name")
+                    && let Some(symbol) = self.scoped_symbol(SymbolKind::Type, &name_node, node, source_code)
+                {
+                    self.symbols.push(symbol);
+                }
+            }
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
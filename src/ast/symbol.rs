@@ -1,3 +1,5 @@
+use tree_sitter::Node;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SymbolKind {
@@ -10,6 +12,49 @@ pub enum SymbolKind {
     Trait,
     Interface,
     Type,
+    Union,
+    Record,
+    Constructor,
+    Destructor,
+    Operator,
+    Field,
+    EnumMember,
+    Namespace,
+    Property,
+    Event,
+    Module,
+    Constant,
+    Test,
+    Import,
+    Export,
+    Impl,
+    Macro,
+    Section,
+    CodeBlock,
+    Table,
+    View,
+    Index,
+    Selector,
+    Element,
+}
+
+/// Who may use a symbol, as declared by its language: Go and JavaScript
+/// export names, the others mark them public or not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    Public,
+    Private,
+    Protected,
+    /// Visible within the crate or package only (`pub(crate)`).
+    Internal,
+    Exported,
+}
+
+impl Visibility {
+    pub fn is_public(self) -> bool {
+        matches!(self, Visibility::Public | Visibility::Exported)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -21,4 +66,109 @@ pub struct Symbol {
     pub full_range: std::ops::Range<usize>,
     pub name_range: Option<std::ops::Range<usize>>,
     pub body_range: Option<std::ops::Range<usize>>,
+    /// Qualified name of the enclosing package, namespace or type.
+    pub container: Option<String>,
+    /// `name` prefixed with its container, using the language's separator.
+    pub qualified_name: Option<String>,
+    /// One of several partial declarations (C# `partial class`) that make
+    /// up the full type.
+    pub partial: bool,
+    /// Changes an object defined elsewhere, as SQL `ALTER TABLE` does.
+    pub alteration: bool,
+    /// Index of the notebook cell the symbol is defined in.
+    pub cell_index: Option<usize>,
+    /// Line within that cell where the symbol starts.
+    pub cell_line: Option<usize>,
+    /// Decorators, annotations and attributes on the declaration, as
+    /// written but without their delimiters: `app.get("/orders")` for
+    /// `@app.get("/orders")` and `test` for `#[test]`.
+    pub decorators: Vec<String>,
+    /// `None` for languages that do not declare visibility, or where it is
+    /// not derived yet.
+    pub visibility: Option<Visibility>,
+    /// Template or generic parameters as written, `<typename T, int N>`.
+    pub type_parameters: Option<String>,
+    /// Preprocessor condition the symbol is compiled under, as in
+    /// `defined(USE_GPU) && VERSION > 2`.
+    pub condition: Option<String>,
+    /// Class implementing the symbol, as for a custom element registered
+    /// with `customElements.define`.
+    pub implementation: Option<String>,
+}
+
+impl Symbol {
+    /// A symbol spanning `node`, with all optional details left empty.
+    pub fn new(kind: SymbolKind, name: String, node: &Node) -> Self {
+        Self {
+            kind,
+            name,
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            full_range: node.byte_range(),
+            name_range: None,
+            body_range: None,
+            container: None,
+            qualified_name: None,
+            partial: false,
+            alteration: false,
+            cell_index: None,
+            cell_line: None,
+            decorators: Vec::new(),
+            visibility: None,
+            type_parameters: None,
+            condition: None,
+            implementation: None,
+        }
+    }
+
+    /// A symbol covering `range` of `source`, for formats scanned without
+    /// a syntax tree.
+    pub fn from_span(kind: SymbolKind, name: String, source: &str, range: std::ops::Range<usize>) -> Self {
+        Self {
+            kind,
+            name,
+            start_line: source[..range.start].matches('\n').count() + 1,
+            end_line: source[..range.end].matches('\n').count() + 1,
+            full_range: range,
+            name_range: None,
+            body_range: None,
+            container: None,
+            qualified_name: None,
+            partial: false,
+            alteration: false,
+            cell_index: None,
+            cell_line: None,
+            decorators: Vec::new(),
+            visibility: None,
+            type_parameters: None,
+            condition: None,
+            implementation: None,
+        }
+    }
+
+    /// Whether one of the decorators is `name`, with or without arguments:
+    /// `pytest.fixture` matches `@pytest.fixture(scope="module")`. The
+    /// name may be written with its delimiters, as in `#[test]`.
+    pub fn has_decorator(&self, name: &str) -> bool {
+        let name = name.trim_start_matches(['@', '#', '[']).trim_end_matches(']');
+        self.decorators.iter().any(|decorator| {
+            decorator.strip_prefix(name).is_some_and(|rest| rest.is_empty() || rest.starts_with('('))
+        })
+    }
+}
+
+/// Keep the public API surface: symbols that are neither declared
+/// non-public themselves nor contained in a symbol that is (a `pub fn` in a
+/// private module). Symbols without a visibility are kept. Containers are
+/// expected to precede their members, as visitors report them.
+pub fn retain_public(symbols: &mut Vec<Symbol>) {
+    let mut hidden = std::collections::HashSet::new();
+    symbols.retain(|symbol| {
+        let is_public = symbol.visibility.is_none_or(Visibility::is_public)
+            && symbol.container.as_ref().is_none_or(|container| !hidden.contains(container));
+        if !is_public {
+            hidden.insert(symbol.qualified_name.clone().unwrap_or_else(|| symbol.name.clone()));
+        }
+        is_public
+    });
 }
@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language, get_script_language};
+    use super::super::bash_visitor::BashVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_bash_function_extraction() {
+        let bash_code = r#"#!/usr/bin/env bash
+set -euo pipefail
+
+function build() {
+    local target="$1"
+    tmp=$(mktemp)
+    make "$target"
+}
+
+clean() { rm -rf out; }
+"#;
+
+        let visitor = BashVisitor::new();
+        let language = get_language("build.sh").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(bash_code, visitor).unwrap();
+
+        let functions: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Function)
+            .collect();
+        assert_eq!(functions.len(), 2);
+
+        let build = functions.iter().find(|f| f.name == "build").unwrap();
+        assert_eq!(build.start_line, 4);
+        assert_eq!(build.end_line, 8);
+
+        let clean = functions.iter().find(|f| f.name == "clean").unwrap();
+        assert_eq!(clean.start_line, 10);
+
+        // Function locals are not script-level symbols
+        assert!(!symbols.iter().any(|s| s.name == "target" || s.name == "tmp"));
+    }
+
+    #[test]
+    fn test_bash_variables_and_exports() {
+        let bash_code = r#"
+BUILD_DIR=out
+export PATH="$PATH:/opt/bin" CC=gcc
+export EDITOR
+readonly VERSION=1.2.0
+declare -r CHANNEL=stable
+
+if [ -n "$CI" ]; then
+    MODE=release
+fi
+"#;
+
+        let visitor = BashVisitor::new();
+        let language = get_language("env.sh").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(bash_code, visitor).unwrap();
+
+        let variables: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Variable)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(variables, vec!["BUILD_DIR", "PATH", "CC", "EDITOR", "MODE"]);
+
+        let constants: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Constant)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(constants, vec!["VERSION", "CHANNEL"]);
+
+        let cc = symbols.iter().find(|s| s.name == "CC").unwrap();
+        assert_eq!(cc.start_line, 3);
+    }
+
+    #[test]
+    fn test_bash_sourced_files() {
+        let bash_code = r#"
+source ./lib/common.sh
+. "$HOME/.buildrc"
+echo "done"
+"#;
+
+        let visitor = BashVisitor::new();
+        let language = get_language("main.sh").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(bash_code, visitor).unwrap();
+
+        let imports: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Import)
+            .collect();
+        assert_eq!(imports.len(), 2);
+        assert_eq!(imports[0].name, "./lib/common.sh");
+        assert_eq!(imports[0].start_line, 2);
+        assert_eq!(imports[1].name, "$HOME/.buildrc");
+    }
+
+    #[test]
+    fn test_bash_shebang_detection() {
+        let bash_code = "#!/bin/bash\ndeploy() { echo; }\n";
+
+        let path = std::env::temp_dir().join(format!("fs_query_deploy_{}", std::process::id()));
+        std::fs::write(&path, bash_code).unwrap();
+        let language = get_script_language(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let mut parser = CodeParser::new(language.unwrap()).unwrap();
+        let symbols = parser.extract_symbols(bash_code, "scripts/deploy", None).unwrap();
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "deploy");
+
+        // `env` with flags, versioned interpreters and non-script files
+        let python_code = "#!/usr/bin/env -S python3.12 -u\ndef main():\n    pass\n";
+        let mut parser = CodeParser::new(get_language("x.py").unwrap()).unwrap();
+        let symbols = parser.extract_symbols(python_code, "bin/tool", None).unwrap();
+        assert_eq!(symbols[0].name, "main");
+
+        assert!(get_script_language(std::path::Path::new("build.sh")).is_none());
+    }
+
+    #[test]
+    fn test_bash_filtering() {
+        let bash_code = r#"
+source ./env.sh
+NAME=app
+run() { ./app; }
+"#;
+
+        let language = get_language("run.sh").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut function_filter = HashSet::new();
+        function_filter.insert(SymbolKind::Function);
+
+        let symbols = parser.extract_symbols(bash_code, "run.sh", Some(function_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "run");
+    }
+}
@@ -0,0 +1,266 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{CodeParser, HeaderLanguage, get_language};
+    use super::super::symbol::{SymbolKind, Visibility};
+    use super::super::c_visitor::CVisitor;
+    use super::super::parser::is_cpp_header;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_c_function_extraction() {
+        let c_code = r#"
+static int add(int a, int b) {
+    return a + b;
+}
+
+char *dup_string(const char *s) {
+    return 0;
+}
+
+int old_style(a, b)
+    int a;
+    int b;
+{
+    return a - b;
+}
+"#;
+
+        let visitor = CVisitor::new();
+        let language = get_language("test.c").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(c_code, visitor).unwrap();
+
+        let functions: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Function)
+            .collect();
+
+        assert_eq!(functions.len(), 3);
+
+        let add = functions.iter().find(|f| f.name == "add").unwrap();
+        let dup = functions.iter().find(|f| f.name == "dup_string").unwrap();
+        let old = functions.iter().find(|f| f.name == "old_style").unwrap();
+
+        assert_eq!(add.start_line, 2);
+        assert_eq!(dup.start_line, 6);
+        assert_eq!(old.start_line, 10);
+        assert!(old.body_range.is_some());
+    }
+
+    #[test]
+    fn test_c_cpp_keywords_as_identifiers() {
+        let c_code = r#"
+int new(int class) {
+    return class;
+}
+
+struct node {
+    int this;
+};
+"#;
+
+        let visitor = CVisitor::new();
+        let language = get_language("test.c").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(c_code, visitor).unwrap();
+
+        assert!(symbols.iter().any(|s| s.kind == SymbolKind::Function && s.name == "new"));
+        assert!(symbols.iter().any(|s| s.kind == SymbolKind::Struct && s.name == "node"));
+        assert!(!symbols.iter().any(|s| s.kind == SymbolKind::Class));
+    }
+
+    #[test]
+    fn test_c_types_extraction() {
+        let c_code = r#"
+struct point {
+    int x, y;
+};
+
+union value {
+    int i;
+    float f;
+};
+
+enum color { RED, GREEN, BLUE };
+
+typedef struct {
+    int w, h;
+} size_t2;
+
+typedef unsigned long ulong, *ulong_ptr;
+
+struct point origin;
+"#;
+
+        let visitor = CVisitor::new();
+        let language = get_language("test.c").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(c_code, visitor).unwrap();
+
+        let structs: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Struct)
+            .collect();
+        assert_eq!(structs.len(), 1);
+        assert_eq!(structs[0].name, "point");
+        assert_eq!(structs[0].start_line, 2);
+
+        let union = symbols.iter().find(|s| s.kind == SymbolKind::Union).unwrap();
+        assert_eq!(union.name, "value");
+        assert_eq!(union.start_line, 6);
+
+        let color = symbols.iter().find(|s| s.kind == SymbolKind::Enum).unwrap();
+        assert_eq!(color.name, "color");
+        assert_eq!(color.start_line, 11);
+
+        let types: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Type)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(types, vec!["size_t2", "ulong", "ulong_ptr"]);
+
+        let origin = symbols.iter().find(|s| s.name == "origin").unwrap();
+        assert_eq!(origin.kind, SymbolKind::Variable);
+    }
+
+    #[test]
+    fn test_c_prototypes_and_globals() {
+        let c_code = r#"
+int parse(const char *input, size_t len);
+void (*on_error)(int code);
+extern int verbose;
+static const char *names[4];
+
+void run(void) {
+    int local = 0;
+}
+"#;
+
+        let visitor = CVisitor::new();
+        let language = get_language("test.c").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(c_code, visitor).unwrap();
+
+        let parse = symbols.iter().find(|s| s.name == "parse").unwrap();
+        assert_eq!(parse.kind, SymbolKind::Function);
+        assert_eq!(parse.start_line, 2);
+        assert!(parse.body_range.is_none());
+
+        let on_error = symbols.iter().find(|s| s.name == "on_error").unwrap();
+        assert_eq!(on_error.kind, SymbolKind::Variable);
+
+        assert!(symbols.iter().any(|s| s.kind == SymbolKind::Variable && s.name == "verbose"));
+        assert!(symbols.iter().any(|s| s.kind == SymbolKind::Variable && s.name == "names"));
+        assert!(!symbols.iter().any(|s| s.name == "local"));
+    }
+
+    #[test]
+    fn test_c_macros() {
+        let c_code = r#"
+#ifndef ORDERS_H
+#define ORDERS_H
+
+#define MAX_ORDERS 64
+#define MIN(a, b) ((a) < (b) ? (a) : (b))
+
+#ifdef HAVE_SSL
+int send_secure(const char *data);
+#else
+int send_plain(const char *data);
+#endif
+
+#endif
+"#;
+
+        let visitor = CVisitor::new();
+        let language = get_language("orders.c").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(c_code, visitor).unwrap();
+
+        // The include guard is neither a macro nor a condition
+        let items: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.start_line, s.end_line, s.condition.as_deref()))
+            .collect();
+        assert_eq!(items, vec![
+            ("MAX_ORDERS", SymbolKind::Macro, 5, 5, None),
+            ("MIN", SymbolKind::Macro, 6, 6, None),
+            ("send_secure", SymbolKind::Function, 9, 9, Some("defined(HAVE_SSL)")),
+            ("send_plain", SymbolKind::Function, 11, 11, Some("!defined(HAVE_SSL)")),
+        ]);
+        assert_eq!(symbols[1].qualified_name.as_deref(), Some("MIN"));
+
+        parser.set_defines(Some(&["HAVE_SSL".to_string()]));
+        let symbols = parser.extract_symbols(c_code, "orders.c", None).unwrap();
+        let names: Vec<_> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["MAX_ORDERS", "MIN", "send_secure"]);
+    }
+
+    #[test]
+    fn test_c_header_detection() {
+        let c_header = r#"
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct list list_t;
+list_t *list_new(void);
+
+#ifdef __cplusplus
+}
+#endif
+"#;
+        let cpp_header = r#"
+namespace util {
+class Buffer {
+public:
+    void clear();
+};
+}
+"#;
+
+        assert!(!is_cpp_header(c_header));
+        assert!(is_cpp_header(cpp_header));
+
+        // `.h` files start with the C++ grammar and are switched to C by content
+        let language = get_language("list.h").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.extract_symbols(c_header, "list.h", None).unwrap();
+        assert!(symbols.iter().any(|s| s.kind == SymbolKind::Type && s.name == "list_t"));
+        assert!(symbols.iter().any(|s| s.kind == SymbolKind::Function && s.name == "list_new"));
+        assert!(symbols.iter().all(|s| s.visibility.is_none()));
+
+        let symbols = parser.extract_symbols(cpp_header, "buffer.h", None).unwrap();
+        assert!(symbols.iter().any(|s| s.kind == SymbolKind::Class && s.name == "Buffer"));
+
+        // A configured language wins over the guess; only C++ symbols are
+        // given a visibility
+        parser.set_header_language(Some(HeaderLanguage::Cpp));
+        let symbols = parser.extract_symbols(c_header, "list.h", None).unwrap();
+        let list_new = symbols.iter().find(|s| s.name == "list_new").unwrap();
+        assert_eq!(list_new.visibility, Some(Visibility::Public));
+    }
+
+    #[test]
+    fn test_c_filtering() {
+        let c_code = r#"
+struct item { int id; };
+typedef int item_id;
+void process(struct item *it) {}
+"#;
+
+        let language = get_language("test.c").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut type_filter = HashSet::new();
+        type_filter.insert(SymbolKind::Type);
+
+        let symbols = parser.extract_symbols(c_code, "test.c", Some(type_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "item_id");
+    }
+}
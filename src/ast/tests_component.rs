@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::component_visitor::ComponentVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_vue_component_regions() {
+        let vue_code = r#"<template>
+  <button @click="increment">{{ count }}</button>
+</template>
+
+<script>
+import { ref } from 'vue'
+
+export function increment() {
+  count.value++
+}
+</script>
+
+<style scoped lang="scss">
+button { color: red; }
+</style>
+"#;
+
+        let visitor = ComponentVisitor::new("Counter");
+        let language = get_language("Counter.vue").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(vue_code, visitor).unwrap();
+
+        let component = &symbols[0];
+        assert_eq!(component.kind, SymbolKind::Class);
+        assert_eq!(component.name, "Counter");
+        assert_eq!(component.start_line, 1);
+        assert_eq!(component.end_line, 15);
+
+        let regions: Vec<_> = symbols[1..].iter()
+            .map(|s| (s.name.as_str(), s.kind, s.start_line, s.end_line))
+            .collect();
+        assert_eq!(regions, vec![
+            ("html", SymbolKind::CodeBlock, 1, 3),
+            ("js", SymbolKind::CodeBlock, 5, 11),
+            ("scss", SymbolKind::CodeBlock, 13, 15),
+        ]);
+        assert!(symbols[1..].iter().all(|s| s.container.as_deref() == Some("Counter")));
+
+        let style = &symbols[3];
+        assert_eq!(&vue_code[style.name_range.clone().unwrap()], "scss");
+        assert_eq!(&vue_code[style.body_range.clone().unwrap()], "\nbutton { color: red; }\n");
+    }
+
+    #[test]
+    fn test_svelte_script_injection() {
+        let svelte_code = r#"<script context="module">
+  export const prerender = true;
+</script>
+
+<script>
+  export let name;
+  function greet() { alert(name) }
+</script>
+
+{#if name}
+  <h1 on:click={greet}>Hello {name}!</h1>
+{/if}
+"#;
+
+        let language = get_language("Hello.svelte").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.extract_symbols(svelte_code, "src/Hello.svelte", None).unwrap();
+
+        let found: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.start_line))
+            .collect();
+        assert_eq!(found, vec![
+            ("Hello", SymbolKind::Class, 1),
+            ("js", SymbolKind::CodeBlock, 1),
+            ("prerender", SymbolKind::Variable, 2),
+            ("js", SymbolKind::CodeBlock, 5),
+            ("name", SymbolKind::Variable, 6),
+            ("greet", SymbolKind::Function, 7),
+            ("html", SymbolKind::CodeBlock, 10),
+        ]);
+
+        // Ranges of script symbols point into the component file
+        let greet = symbols.iter().find(|s| s.name == "greet").unwrap();
+        assert_eq!(&svelte_code[greet.name_range.clone().unwrap()], "greet");
+    }
+
+    #[test]
+    fn test_component_filtering() {
+        let vue_code = "<script>\nexport default { name: 'Empty' }\nfunction helper() {}\n</script>\n";
+
+        let language = get_language("Empty.vue").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut function_filter = HashSet::new();
+        function_filter.insert(SymbolKind::Function);
+
+        let symbols = parser.extract_symbols(vue_code, "Empty.vue", Some(function_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "helper");
+        assert_eq!(symbols[0].start_line, 3);
+    }
+}
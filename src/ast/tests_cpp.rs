@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use super::super::{CodeParser, get_language};
-    use super::super::symbol::SymbolKind;
+    use super::super::symbol::{SymbolKind, Visibility};
     use super::super::cpp_visitor::CppVisitor;
     use std::collections::HashSet;
 
@@ -27,15 +27,19 @@ void dd::test() {}
             .filter(|s| s.kind == SymbolKind::Function)
             .collect();
         
-        assert_eq!(functions.len(), 3);
+        assert_eq!(functions.len(), 2);
         
         let simple = functions.iter().find(|f| f.name == "simple_function").unwrap();
         let namespaced = functions.iter().find(|f| f.name == "namespaced_function").unwrap();
-        let dd_test = functions.iter().find(|f| f.name == "dd::test").unwrap();
+        // `dd` is not a namespace of this file, so `test` is a method of it
+        let dd_test = symbols.iter().find(|f| f.name == "test").unwrap();
         
         assert_eq!(simple.start_line, 2);
         assert_eq!(namespaced.start_line, 5);
+        assert_eq!(namespaced.qualified_name.as_deref(), Some("ns::namespaced_function"));
         assert_eq!(dd_test.start_line, 8);
+        assert_eq!(dd_test.kind, SymbolKind::Method);
+        assert_eq!(dd_test.qualified_name.as_deref(), Some("dd::test"));
     }
 
     #[test]
@@ -97,6 +101,337 @@ void function() {
         assert_eq!(pi.start_line, 3);
     }
 
+    #[test]
+    fn test_cpp_variable_declarator_names() {
+        let cpp_code = r#"
+auto (*fp)(int) -> int;
+int *ptr = nullptr;
+int& ref = other;
+char buffer[64];
+int *make(int), global = 3;
+"#;
+
+        let visitor = CppVisitor::new();
+        let language = get_language("test.cpp").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cpp_code, visitor).unwrap();
+
+        // Variables are named by their identifier, not the declarator text
+        let items: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, &cpp_code[s.name_range.clone().unwrap()]))
+            .collect();
+        assert_eq!(items, vec![
+            ("fp", SymbolKind::Variable, "fp"),
+            ("ptr", SymbolKind::Variable, "ptr"),
+            ("ref", SymbolKind::Variable, "ref"),
+            ("buffer", SymbolKind::Variable, "buffer"),
+            ("make", SymbolKind::Function, "make"),
+            ("global", SymbolKind::Variable, "global"),
+        ]);
+    }
+
+    #[test]
+    fn test_cpp_type_extraction() {
+        let cpp_code = r#"
+enum Color { Red, Green };
+enum class Mode : int { Fast, Safe };
+enum Color current;
+
+using Id = int;
+template <typename T>
+using Vec = std::vector<T>;
+
+typedef unsigned long size_type, *size_ptr;
+typedef void (*Callback)(int);
+"#;
+
+        let visitor = CppVisitor::new();
+        let language = get_language("test.cpp").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cpp_code, visitor).unwrap();
+
+        let types: Vec<_> = symbols.iter()
+            .filter(|s| s.kind != SymbolKind::Variable && s.container.is_none())
+            .map(|s| (s.name.as_str(), s.kind, s.start_line))
+            .collect();
+        assert_eq!(types, vec![
+            ("Color", SymbolKind::Enum, 2),
+            ("Mode", SymbolKind::Enum, 3),
+            ("Id", SymbolKind::Type, 6),
+            ("Vec", SymbolKind::Type, 7),
+            ("size_type", SymbolKind::Type, 10),
+            ("size_ptr", SymbolKind::Type, 10),
+            ("Callback", SymbolKind::Type, 11),
+        ]);
+    }
+
+    #[test]
+    fn test_cpp_members() {
+        let cpp_code = r#"
+class Order {
+public:
+    int id;
+    double a, *b;
+    static const int MAX = 10;
+    int (*callback)(int);
+    void pay();
+    Order *next();
+};
+enum class Mode { Fast, Safe };
+"#;
+
+        let visitor = CppVisitor::new();
+        let language = get_language("test.cpp").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cpp_code, visitor).unwrap();
+
+        let members: Vec<_> = symbols.iter()
+            .filter(|s| s.container.is_some())
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.kind))
+            .collect();
+        assert_eq!(members, vec![
+            ("Order::id", SymbolKind::Field),
+            ("Order::a", SymbolKind::Field),
+            ("Order::b", SymbolKind::Field),
+            ("Order::MAX", SymbolKind::Constant),
+            ("Order::callback", SymbolKind::Field),
+            ("Order::pay", SymbolKind::Method),
+            ("Order::next", SymbolKind::Method),
+            ("Mode::Fast", SymbolKind::EnumMember),
+            ("Mode::Safe", SymbolKind::EnumMember),
+        ]);
+    }
+
+    #[test]
+    fn test_cpp_attributes() {
+        let cpp_code = r#"
+[[nodiscard, deprecated("use total")]] int sum() { return 0; }
+class [[deprecated]] Legacy {
+    [[no_unique_address]] Empty empty;
+};
+"#;
+
+        let visitor = CppVisitor::new();
+        let language = get_language("test.cpp").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cpp_code, visitor).unwrap();
+
+        let decorators: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.decorators.clone()))
+            .collect();
+        assert_eq!(decorators, vec![
+            ("sum", vec!["nodiscard".to_string(), "deprecated(\"use total\")".to_string()]),
+            ("Legacy", vec!["deprecated".to_string()]),
+            ("empty", vec!["no_unique_address".to_string()]),
+        ]);
+    }
+
+    #[test]
+    fn test_cpp_visibility() {
+        let cpp_code = r#"
+class Account {
+    int balance;
+public:
+    void deposit(int amount) {}
+protected:
+    int limit;
+};
+struct Point { int x; };
+static int helper() { return 0; }
+"#;
+
+        let visitor = CppVisitor::new();
+        let language = get_language("test.cpp").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cpp_code, visitor).unwrap();
+
+        let visibility: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.visibility.unwrap()))
+            .collect();
+        assert_eq!(visibility, vec![
+            ("Account", Visibility::Public),
+            ("balance", Visibility::Private),
+            ("deposit", Visibility::Public),
+            ("limit", Visibility::Protected),
+            ("Point", Visibility::Public),
+            ("x", Visibility::Public),
+            ("helper", Visibility::Private),
+        ]);
+    }
+
+    #[test]
+    fn test_cpp_namespaces_and_special_members() {
+        let cpp_code = r#"
+namespace store::orders {
+template <typename T, int N = 3>
+class Cart {
+public:
+    Cart();
+    ~Cart();
+    bool operator==(const Cart&) const;
+    operator bool() const { return true; }
+    template <typename U> U total() const;
+    friend void swap(Cart&, Cart&);
+private:
+    void recalculate();
+};
+
+void helper();
+}
+
+namespace {
+int counter;
+}
+
+template <typename T, int N>
+store::orders::Cart<T, N>::Cart() {}
+void store::orders::Cart::recalculate() {}
+void store::orders::helper() {}
+"#;
+
+        let visitor = CppVisitor::new();
+        let language = get_language("test.cpp").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cpp_code, visitor).unwrap();
+
+        let items: Vec<_> = symbols.iter()
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.kind, s.start_line))
+            .collect();
+        assert_eq!(items, vec![
+            ("store::orders", SymbolKind::Namespace, 2),
+            ("store::orders::Cart", SymbolKind::Class, 3),
+            ("store::orders::Cart::Cart", SymbolKind::Constructor, 6),
+            ("store::orders::Cart::~Cart", SymbolKind::Destructor, 7),
+            ("store::orders::Cart::operator==", SymbolKind::Operator, 8),
+            ("store::orders::Cart::operator bool", SymbolKind::Operator, 9),
+            ("store::orders::Cart::total", SymbolKind::Method, 10),
+            ("store::orders::Cart::recalculate", SymbolKind::Method, 13),
+            ("store::orders::helper", SymbolKind::Function, 16),
+            ("counter", SymbolKind::Variable, 20),
+            ("store::orders::Cart::Cart", SymbolKind::Constructor, 23),
+            ("store::orders::Cart::recalculate", SymbolKind::Method, 25),
+            ("store::orders::helper", SymbolKind::Function, 26),
+        ]);
+
+        let cart = &symbols[1];
+        assert_eq!(cart.name, "Cart");
+        assert_eq!(cart.container.as_deref(), Some("store::orders"));
+        assert_eq!(cart.type_parameters.as_deref(), Some("<typename T, int N = 3>"));
+        assert_eq!(symbols[6].type_parameters.as_deref(), Some("<typename U>"));
+
+        // Out-of-line definitions take the access of their declaration, and
+        // anonymous namespaces hide their members
+        let visibility: Vec<_> = symbols.iter()
+            .filter(|s| matches!(s.name.as_str(), "recalculate" | "counter"))
+            .map(|s| (s.name.as_str(), s.visibility.unwrap()))
+            .collect();
+        assert_eq!(visibility, vec![
+            ("recalculate", Visibility::Private),
+            ("counter", Visibility::Private),
+            ("recalculate", Visibility::Private),
+        ]);
+    }
+
+    #[test]
+    fn test_cpp_macros_and_conditions() {
+        let cpp_code = r#"
+#define BUFFER_SIZE 256
+#define CLAMP(x, lo, hi) \
+    ((x) < (lo) ? (lo) : (x) > (hi) ? (hi) : (x))
+
+class Driver {
+public:
+#if defined(USE_DMA) && BOARD_REV >= 2
+    void start_dma();
+#elif BOARD_REV == 1
+    void start_polling();
+#else
+    void start_legacy();
+#endif
+#ifdef DEBUG
+#ifndef NDEBUG
+    int trace_level;
+#endif
+#endif
+};
+"#;
+
+        let visitor = CppVisitor::new();
+        let language = get_language("test.cpp").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cpp_code, visitor).unwrap();
+
+        let items: Vec<_> = symbols.iter()
+            .map(|s| (s.qualified_name.as_deref().unwrap_or(&s.name), s.kind, s.condition.as_deref()))
+            .collect();
+        assert_eq!(items, vec![
+            ("BUFFER_SIZE", SymbolKind::Macro, None),
+            ("CLAMP", SymbolKind::Macro, None),
+            ("Driver", SymbolKind::Class, None),
+            ("Driver::start_dma", SymbolKind::Method, Some("defined(USE_DMA) && BOARD_REV >= 2")),
+            ("Driver::start_polling", SymbolKind::Method, Some("!(defined(USE_DMA) && BOARD_REV >= 2) && BOARD_REV == 1")),
+            ("Driver::start_legacy", SymbolKind::Method, Some("!(defined(USE_DMA) && BOARD_REV >= 2) && !(BOARD_REV == 1)")),
+            ("Driver::trace_level", SymbolKind::Field, Some("defined(DEBUG) && !defined(NDEBUG)")),
+        ]);
+
+        let clamp = &symbols[1];
+        assert_eq!((clamp.start_line, clamp.end_line), (3, 4));
+    }
+
+    #[test]
+    fn test_cpp_defines() {
+        let cpp_code = r#"
+#define FEATURE_LOGGING
+#if BOARD_REV >= 2 && !defined(LEGACY)
+void rev2();
+#elif HAS_FEATURE(dma)
+void maybe_dma();
+#else
+void fallback();
+#endif
+#ifdef FEATURE_LOGGING
+void log();
+#endif
+#undef FEATURE_LOGGING
+#ifdef FEATURE_LOGGING
+void log_again();
+#endif
+#ifdef __cplusplus
+void cpp_only();
+#endif
+"#;
+
+        let language = get_language("test.cpp").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+        let names = |parser: &mut CodeParser| -> Vec<String> {
+            parser.extract_symbols(cpp_code, "test.cpp", None).unwrap().into_iter()
+                .filter(|s| s.kind == SymbolKind::Function)
+                .map(|s| s.name)
+                .collect()
+        };
+
+        // Without defines, every branch is kept
+        assert_eq!(names(&mut parser).len(), 6);
+
+        parser.set_defines(Some(&["BOARD_REV=3".to_string()]));
+        assert_eq!(names(&mut parser), vec!["rev2", "log", "cpp_only"]);
+
+        // A function-like macro cannot be evaluated, so its branch is kept,
+        // and so is `#else` since it may be taken
+        parser.set_defines(Some(&["BOARD_REV=1".to_string()]));
+        assert_eq!(names(&mut parser), vec!["maybe_dma", "fallback", "log", "cpp_only"]);
+
+        parser.set_defines(Some(&["BOARD_REV=2".to_string(), "LEGACY".to_string()]));
+        assert_eq!(names(&mut parser), vec!["maybe_dma", "fallback", "log", "cpp_only"]);
+    }
+
     #[test]
     fn test_cpp_filtering() {
         let cpp_code = r#"
@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::csharp_visitor::CSharpVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_csharp_block_namespace() {
+        let cs_code = r#"
+namespace Acme.Billing
+{
+    public class Invoice
+    {
+        private decimal total, tax;
+
+        public Invoice() {}
+
+        public string Number { get; set; }
+
+        public event EventHandler Paid;
+
+        public void Send() {}
+    }
+
+    namespace Internal
+    {
+        struct Money {}
+    }
+}
+"#;
+
+        let visitor = CSharpVisitor::new();
+        let language = get_language("Invoice.cs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cs_code, visitor).unwrap();
+
+        let namespace = symbols.iter().find(|s| s.name == "Acme.Billing").unwrap();
+        assert_eq!(namespace.kind, SymbolKind::Namespace);
+        assert_eq!(namespace.start_line, 2);
+
+        let invoice = symbols.iter().find(|s| s.name == "Invoice" && s.kind == SymbolKind::Class).unwrap();
+        assert_eq!(invoice.start_line, 4);
+        assert_eq!(invoice.qualified_name.as_deref(), Some("Acme.Billing.Invoice"));
+
+        let fields: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Field)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(fields, vec!["total", "tax"]);
+
+        assert!(symbols.iter().any(|s| s.kind == SymbolKind::Constructor && s.name == "Invoice"));
+
+        let number = symbols.iter().find(|s| s.name == "Number").unwrap();
+        assert_eq!(number.kind, SymbolKind::Property);
+        assert_eq!(number.container.as_deref(), Some("Acme.Billing.Invoice"));
+
+        let paid = symbols.iter().find(|s| s.name == "Paid").unwrap();
+        assert_eq!(paid.kind, SymbolKind::Event);
+
+        let send = symbols.iter().find(|s| s.name == "Send").unwrap();
+        assert_eq!(send.kind, SymbolKind::Method);
+        assert_eq!(send.start_line, 14);
+
+        let money = symbols.iter().find(|s| s.name == "Money").unwrap();
+        assert_eq!(money.kind, SymbolKind::Struct);
+        assert_eq!(money.qualified_name.as_deref(), Some("Acme.Billing.Internal.Money"));
+    }
+
+    #[test]
+    fn test_csharp_file_scoped_namespace() {
+        let cs_code = r#"
+namespace Acme.Shipping;
+
+public record Parcel(string Id);
+
+public record struct Weight(double Grams);
+
+public interface ICarrier
+{
+    void Ship(Parcel parcel);
+}
+
+public enum Priority { Low, High }
+
+public delegate void ShippedHandler(Parcel parcel);
+"#;
+
+        let visitor = CSharpVisitor::new();
+        let language = get_language("Shipping.cs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cs_code, visitor).unwrap();
+
+        let namespace = symbols.iter().find(|s| s.kind == SymbolKind::Namespace).unwrap();
+        assert_eq!(namespace.name, "Acme.Shipping");
+        assert_eq!(namespace.start_line, 2);
+        assert_eq!(namespace.end_line, 16);
+
+        let parcel = symbols.iter().find(|s| s.name == "Parcel").unwrap();
+        assert_eq!(parcel.kind, SymbolKind::Record);
+        assert_eq!(parcel.qualified_name.as_deref(), Some("Acme.Shipping.Parcel"));
+
+        let weight = symbols.iter().find(|s| s.name == "Weight").unwrap();
+        assert_eq!(weight.kind, SymbolKind::Record);
+
+        let carrier = symbols.iter().find(|s| s.name == "ICarrier").unwrap();
+        assert_eq!(carrier.kind, SymbolKind::Interface);
+        let ship = symbols.iter().find(|s| s.name == "Ship").unwrap();
+        assert_eq!(ship.qualified_name.as_deref(), Some("Acme.Shipping.ICarrier.Ship"));
+
+        let priority = symbols.iter().find(|s| s.name == "Priority").unwrap();
+        assert_eq!(priority.kind, SymbolKind::Enum);
+
+        let handler = symbols.iter().find(|s| s.name == "ShippedHandler").unwrap();
+        assert_eq!(handler.kind, SymbolKind::Type);
+        assert_eq!(handler.start_line, 15);
+    }
+
+    #[test]
+    fn test_csharp_partial_classes() {
+        let cs_code = r#"
+public partial class Player
+{
+    void Update() {}
+}
+
+public partial class Player
+{
+    void Render() {}
+}
+
+public class Enemy {}
+"#;
+
+        let visitor = CSharpVisitor::new();
+        let language = get_language("Player.cs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cs_code, visitor).unwrap();
+
+        let players: Vec<_> = symbols.iter()
+            .filter(|s| s.name == "Player")
+            .collect();
+        assert_eq!(players.len(), 2);
+        assert!(players.iter().all(|s| s.partial));
+
+        let enemy = symbols.iter().find(|s| s.name == "Enemy").unwrap();
+        assert!(!enemy.partial);
+
+        let render = symbols.iter().find(|s| s.name == "Render").unwrap();
+        assert_eq!(render.container.as_deref(), Some("Player"));
+    }
+
+    #[test]
+    fn test_csharp_constructors_and_destructors() {
+        let cs_code = r#"
+class Order
+{
+    public Order() {}
+    ~Order() {}
+}
+"#;
+
+        let visitor = CSharpVisitor::new();
+        let language = get_language("Order.cs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(cs_code, visitor).unwrap();
+
+        let members: Vec<_> = symbols.iter()
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.kind, s.start_line))
+            .collect();
+        assert_eq!(members, vec![
+            ("Order", SymbolKind::Class, 2),
+            ("Order.Order", SymbolKind::Constructor, 4),
+            ("Order.~Order", SymbolKind::Destructor, 5),
+        ]);
+    }
+
+    #[test]
+    fn test_csharp_filtering() {
+        let cs_code = r#"
+class Config
+{
+    public int Port { get; set; }
+    public string Host { get; }
+    public void Load() {}
+}
+"#;
+
+        let language = get_language("Config.cs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut property_filter = HashSet::new();
+        property_filter.insert(SymbolKind::Property);
+
+        let symbols = parser.extract_symbols(cs_code, "Config.cs", Some(property_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 2);
+        assert!(symbols.iter().any(|s| s.name == "Port"));
+        assert!(symbols.iter().any(|s| s.name == "Host"));
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::dockerfile_visitor::DockerfileVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_dockerfile_stage_extraction() {
+        let dockerfile = r#"ARG RUST_VERSION=1.80
+FROM rust:${RUST_VERSION} AS builder
+ENV CARGO_HOME=/cargo PATH=/cargo/bin:$PATH
+WORKDIR /src
+RUN cargo build --release
+# the runtime image
+
+FROM debian:bookworm-slim
+ENV RUST_LOG info
+COPY --from=builder /src/target/release/app /app
+"#;
+
+        let visitor = DockerfileVisitor::new();
+        let language = get_language("Dockerfile").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(dockerfile, visitor).unwrap();
+
+        let builder = symbols.iter().find(|s| s.kind == SymbolKind::Module).unwrap();
+        assert_eq!(builder.name, "builder");
+        assert_eq!(builder.start_line, 2);
+        assert_eq!(builder.end_line, 5);
+
+        let variables: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Variable)
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        // The unnamed runtime stage has no symbol, so its variables are unscoped
+        assert_eq!(variables, vec!["RUST_VERSION", "builder.CARGO_HOME", "builder.PATH", "RUST_LOG"]);
+
+        let rust_version = &symbols[0];
+        assert_eq!(&dockerfile[rust_version.body_range.clone().unwrap()], "1.80");
+    }
+
+    #[test]
+    fn test_dockerfile_filtering() {
+        let dockerfile = "FROM node:20 AS deps\nARG NPM_TOKEN\nFROM deps AS build\n";
+
+        let mut parser = CodeParser::new(get_language("Dockerfile.prod").unwrap()).unwrap();
+
+        let mut stage_filter = HashSet::new();
+        stage_filter.insert(SymbolKind::Module);
+
+        let symbols = parser.extract_symbols(dockerfile, "docker/Dockerfile.prod", Some(stage_filter)).unwrap();
+
+        let names: Vec<_> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["deps", "build"]);
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::elixir_visitor::ElixirVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_elixir_module_extraction() {
+        let elixir_code = r#"defmodule MyApp.Accounts do
+  @moduledoc "Accounts"
+  alias MyApp.Repo
+
+  defmodule User do
+    defstruct [:name, :email]
+  end
+
+  @doc "Fetches a user"
+  @spec get_user(integer()) :: User.t()
+  def get_user(id) do
+    Repo.get(User, id)
+  end
+
+  def get_user(id, opts), do: nil
+
+  defp helper(x), do: x
+end
+"#;
+
+        let visitor = ElixirVisitor::new();
+        let language = get_language("accounts.ex").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(elixir_code, visitor).unwrap();
+
+        let accounts = symbols.iter().find(|s| s.name == "Accounts").unwrap();
+        assert_eq!(accounts.kind, SymbolKind::Module);
+        assert_eq!(accounts.container.as_deref(), Some("MyApp"));
+        assert_eq!(accounts.start_line, 1);
+        assert_eq!(accounts.end_line, 18);
+
+        let user = symbols.iter().find(|s| s.name == "User" && s.kind == SymbolKind::Module).unwrap();
+        assert_eq!(user.qualified_name.as_deref(), Some("MyApp.Accounts.User"));
+
+        let user_struct = symbols.iter().find(|s| s.kind == SymbolKind::Struct).unwrap();
+        assert_eq!(user_struct.qualified_name.as_deref(), Some("MyApp.Accounts.User"));
+        assert_eq!(user_struct.start_line, 6);
+
+        // Functions are told apart by arity, and the spec is part of the definition
+        let get_user: Vec<_> = symbols.iter().filter(|s| s.name == "get_user").collect();
+        assert_eq!(get_user.len(), 2);
+        assert_eq!(get_user[0].qualified_name.as_deref(), Some("MyApp.Accounts.get_user/1"));
+        assert_eq!(get_user[0].start_line, 10);
+        assert_eq!(get_user[0].end_line, 13);
+        assert_eq!(get_user[1].qualified_name.as_deref(), Some("MyApp.Accounts.get_user/2"));
+        assert_eq!(get_user[1].start_line, 15);
+
+        let helper = symbols.iter().find(|s| s.name == "helper").unwrap();
+        assert_eq!(helper.kind, SymbolKind::Function);
+        assert_eq!(helper.container.as_deref(), Some("MyApp.Accounts"));
+    }
+
+    #[test]
+    fn test_elixir_clauses_and_macros() {
+        let elixir_code = r#"defmodule Math do
+  def fact(0), do: 1
+  def fact(n) when n > 0, do: n * fact(n - 1)
+
+  def zero, do: 0
+
+  defmacro unless(condition, do: block) do
+    quote do
+      def generated, do: :ok
+    end
+  end
+end
+"#;
+
+        let visitor = ElixirVisitor::new();
+        let language = get_language("math.ex").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(elixir_code, visitor).unwrap();
+
+        let fact: Vec<_> = symbols.iter().filter(|s| s.name == "fact").collect();
+        assert_eq!(fact.len(), 1);
+        assert_eq!(fact[0].start_line, 2);
+        assert_eq!(fact[0].end_line, 3);
+
+        let zero = symbols.iter().find(|s| s.name == "zero").unwrap();
+        assert_eq!(zero.qualified_name.as_deref(), Some("Math.zero/0"));
+
+        let unless = symbols.iter().find(|s| s.name == "unless").unwrap();
+        assert_eq!(unless.kind, SymbolKind::Macro);
+        assert_eq!(unless.end_line, 11);
+
+        // Definitions inside macro bodies are not module symbols
+        assert!(!symbols.iter().any(|s| s.name == "generated"));
+    }
+
+    #[test]
+    fn test_elixir_filtering() {
+        let elixir_code = r#"
+defmodule Greeter do
+  def hello(name), do: "hi #{name}"
+end
+"#;
+
+        let language = get_language("greeter.ex").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut module_filter = HashSet::new();
+        module_filter.insert(SymbolKind::Module);
+
+        let symbols = parser.extract_symbols(elixir_code, "greeter.ex", Some(module_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "Greeter");
+    }
+}
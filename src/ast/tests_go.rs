@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::{SymbolKind, Visibility, CodeParser, get_language};
     use super::super::go_visitor::GoVisitor;
     use std::collections::HashSet;
 
@@ -103,6 +103,154 @@ func main() {
         assert!(variables.iter().any(|v| v.name == "pi"));
     }
 
+    #[test]
+    fn test_go_type_extraction() {
+        let go_code = r#"
+package main
+
+type Reader interface {
+    Read(p []byte) (int, error)
+}
+
+type UserID string
+
+type Handler func(w Writer)
+
+type Any = interface{}
+
+type Point struct {
+    X, Y int
+}
+"#;
+
+        let visitor = GoVisitor::new();
+        let language = get_language("test.go").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(go_code, visitor).unwrap();
+
+        let types: Vec<_> = symbols.iter()
+            .filter(|s| s.container.is_none())
+            .map(|s| (s.name.as_str(), s.kind, s.start_line))
+            .collect();
+        assert_eq!(types, vec![
+            ("Reader", SymbolKind::Interface, 4),
+            ("UserID", SymbolKind::Type, 8),
+            ("Handler", SymbolKind::Type, 10),
+            ("Any", SymbolKind::Type, 12),
+            ("Point", SymbolKind::Struct, 14),
+        ]);
+
+        let reader = &symbols[0];
+        assert_eq!(reader.end_line, 6);
+    }
+
+    #[test]
+    fn test_go_struct_fields() {
+        let go_code = r#"
+package main
+
+type Order struct {
+    ID, Ref string
+    Base
+    io.Reader
+    Items []Item `json:"items"`
+}
+"#;
+
+        let visitor = GoVisitor::new();
+        let language = get_language("test.go").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(go_code, visitor).unwrap();
+
+        let fields: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Field)
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.start_line))
+            .collect();
+        assert_eq!(fields, vec![
+            ("Order.ID", 5),
+            ("Order.Ref", 5),
+            ("Order.Base", 6),
+            ("Order.Reader", 7),
+            ("Order.Items", 8),
+        ]);
+        assert!(symbols.iter().filter(|s| s.kind == SymbolKind::Field).all(|s| s.container.as_deref() == Some("Order")));
+    }
+
+    #[test]
+    fn test_go_constants() {
+        let go_code = r#"
+package main
+
+type Status int
+
+const (
+    Pending Status = iota
+    Paid
+    _
+)
+
+const Max = 3
+
+const (
+    timeout int = 5
+    a, b = 1, 2
+)
+"#;
+
+        let visitor = GoVisitor::new();
+        let language = get_language("test.go").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(go_code, visitor).unwrap();
+
+        let constants: Vec<_> = symbols.iter()
+            .filter(|s| matches!(s.kind, SymbolKind::Constant | SymbolKind::EnumMember))
+            .map(|s| (s.qualified_name.as_deref().unwrap_or(&s.name), s.kind, s.start_line))
+            .collect();
+        assert_eq!(constants, vec![
+            ("Status.Pending", SymbolKind::EnumMember, 7),
+            ("Status.Paid", SymbolKind::EnumMember, 8),
+            ("Max", SymbolKind::Constant, 12),
+            ("timeout", SymbolKind::Constant, 15),
+            ("a", SymbolKind::Constant, 16),
+            ("b", SymbolKind::Constant, 16),
+        ]);
+    }
+
+    #[test]
+    fn test_go_visibility() {
+        let go_code = r#"
+package orders
+
+type Order struct {
+    ID    string
+    notes string
+}
+
+func New() *Order { return nil }
+func validate(o *Order) error { return nil }
+"#;
+
+        let visitor = GoVisitor::new();
+        let language = get_language("test.go").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(go_code, visitor).unwrap();
+
+        let visibility: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.visibility.unwrap()))
+            .collect();
+        assert_eq!(visibility, vec![
+            ("Order", Visibility::Exported),
+            ("ID", Visibility::Exported),
+            ("notes", Visibility::Private),
+            ("New", Visibility::Exported),
+            ("validate", Visibility::Private),
+        ]);
+    }
+
     #[test]
     fn test_go_filtering() {
         let go_code = r#"
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::graphql_visitor::GraphqlVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_graphql_type_extraction() {
+        let graphql_code = r#""""An order"""
+type Order implements Node {
+  id: ID!
+  status: Status
+}
+
+input OrderInput {
+  sku: String!
+}
+
+enum Status { OPEN PAID }
+
+interface Node { id: ID! }
+
+union SearchResult = Order | Customer
+
+scalar DateTime
+"#;
+
+        let visitor = GraphqlVisitor::new();
+        let language = get_language("schema.graphql").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(graphql_code, visitor).unwrap();
+
+        let kinds: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind))
+            .collect();
+        assert_eq!(kinds, vec![
+            ("Order", SymbolKind::Class),
+            ("OrderInput", SymbolKind::Struct),
+            ("Status", SymbolKind::Enum),
+            ("Node", SymbolKind::Interface),
+            ("SearchResult", SymbolKind::Union),
+            ("DateTime", SymbolKind::Type),
+        ]);
+
+        // The description belongs to the type
+        assert_eq!(symbols[0].start_line, 1);
+        assert_eq!(symbols[0].end_line, 5);
+    }
+
+    #[test]
+    fn test_graphql_operation_extraction() {
+        let graphql_code = r#"schema {
+  query: RootQuery
+  mutation: Mutation
+}
+
+type RootQuery {
+  order(id: ID!): Order
+  orders(first: Int): [Order!]!
+}
+
+type Mutation {
+  createOrder(input: OrderInput!): Order
+}
+
+extend type Mutation {
+  cancelOrder(id: ID!): Boolean
+}
+
+query GetOrder($id: ID!) {
+  order(id: $id) { id }
+}
+"#;
+
+        let visitor = GraphqlVisitor::new();
+        let language = get_language("api.gql").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(graphql_code, visitor).unwrap();
+
+        let operations: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Method)
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(operations, vec![
+            "RootQuery.order",
+            "RootQuery.orders",
+            "Mutation.createOrder",
+            "Mutation.cancelOrder",
+        ]);
+
+        let cancel = symbols.iter().find(|s| s.name == "cancelOrder").unwrap();
+        assert_eq!(cancel.start_line, 16);
+
+        let get_order = symbols.iter().find(|s| s.kind == SymbolKind::Function).unwrap();
+        assert_eq!(get_order.name, "GetOrder");
+        assert_eq!(get_order.end_line, 21);
+    }
+
+    #[test]
+    fn test_graphql_filtering() {
+        let graphql_code = "type Query { ping: String }\nenum Color { RED }\n";
+
+        let language = get_language("schema.graphql").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut enum_filter = HashSet::new();
+        enum_filter.insert(SymbolKind::Enum);
+
+        let symbols = parser.extract_symbols(graphql_code, "schema.graphql", Some(enum_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "Color");
+    }
+}
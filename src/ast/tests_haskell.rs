@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::haskell_visitor::HaskellVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_haskell_function_extraction() {
+        let haskell_code = r#"module Data.Shapes (Shape(..), area) where
+
+import qualified Data.Map as M
+import Data.List (sortOn)
+
+area :: Shape -> Double
+area (Circle r) = pi * r * r
+area (Square s) = s * s
+
+origin :: Double
+origin = 0
+
+double x = x * 2
+"#;
+
+        let visitor = HaskellVisitor::new();
+        let language = get_language("Shapes.hs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(haskell_code, visitor).unwrap();
+
+        let module = symbols.iter().find(|s| s.kind == SymbolKind::Module).unwrap();
+        assert_eq!(module.name, "Data.Shapes");
+        assert_eq!(module.start_line, 1);
+
+        let imports: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Import)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(imports, vec!["Data.Map", "Data.List"]);
+
+        // The signature and every equation form one symbol
+        let area: Vec<_> = symbols.iter().filter(|s| s.name == "area").collect();
+        assert_eq!(area.len(), 1);
+        assert_eq!(area[0].kind, SymbolKind::Function);
+        assert_eq!(area[0].start_line, 6);
+        assert_eq!(area[0].end_line, 8);
+        assert_eq!(area[0].qualified_name.as_deref(), Some("Data.Shapes.area"));
+
+        let origin = symbols.iter().find(|s| s.name == "origin").unwrap();
+        assert_eq!(origin.kind, SymbolKind::Variable);
+        assert_eq!(origin.end_line, 11);
+
+        assert_eq!(symbols.iter().find(|s| s.name == "double").unwrap().kind, SymbolKind::Function);
+    }
+
+    #[test]
+    fn test_haskell_types_and_classes() {
+        let haskell_code = r#"module Shapes where
+
+data Shape = Circle Double | Square Double
+  deriving (Show)
+
+newtype Meters = Meters Double
+
+type Name = String
+
+class Describable a where
+  describe :: a -> String
+  name :: a -> Name
+
+instance Describable Shape where
+  describe _ = "shape"
+  name _ = "s"
+"#;
+
+        let visitor = HaskellVisitor::new();
+        let language = get_language("Shapes.hs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(haskell_code, visitor).unwrap();
+
+        let types: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Type)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(types, vec!["Shape", "Meters", "Name"]);
+
+        let class = symbols.iter().find(|s| s.kind == SymbolKind::Trait).unwrap();
+        assert_eq!(class.name, "Describable");
+        assert_eq!(class.start_line, 10);
+        assert_eq!(class.end_line, 12);
+
+        let instance = symbols.iter().find(|s| s.kind == SymbolKind::Impl).unwrap();
+        assert_eq!(instance.name, "Describable Shape");
+        assert_eq!(instance.qualified_name.as_deref(), Some("Shapes.Describable Shape"));
+
+        let methods: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Method)
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(methods, vec![
+            "Shapes.Describable.describe",
+            "Shapes.Describable.name",
+            "Shapes.Describable Shape.describe",
+            "Shapes.Describable Shape.name",
+        ]);
+    }
+
+    #[test]
+    fn test_haskell_filtering() {
+        let haskell_code = r#"module Main where
+
+data Config = Config
+main = pure ()
+"#;
+
+        let language = get_language("Main.hs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut type_filter = HashSet::new();
+        type_filter.insert(SymbolKind::Type);
+
+        let symbols = parser.extract_symbols(haskell_code, "Main.hs", Some(type_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "Config");
+    }
+}
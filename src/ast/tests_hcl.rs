@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::hcl_visitor::HclVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_terraform_block_extraction() {
+        let hcl_code = r#"variable "region" {
+  default = "us-east-1"
+}
+
+locals {
+  name = "app"
+}
+
+resource "aws_instance" "web" {
+  ami = "ami-123"
+}
+
+data "aws_ami" "ubuntu" {
+  most_recent = true
+}
+
+module "vpc" {
+  source = "./vpc"
+}
+
+output "ip" {
+  value = aws_instance.web.public_ip
+}
+
+provider "aws" {
+  region = var.region
+}
+"#;
+
+        let visitor = HclVisitor::new();
+        let language = get_language("main.tf").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(hcl_code, visitor).unwrap();
+
+        let addresses: Vec<_> = symbols.iter()
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.kind))
+            .collect();
+        assert_eq!(addresses, vec![
+            ("var.region", SymbolKind::Variable),
+            ("local.name", SymbolKind::Variable),
+            ("aws_instance.web", SymbolKind::Class),
+            ("data.aws_ami.ubuntu", SymbolKind::Class),
+            ("module.vpc", SymbolKind::Module),
+            ("output.ip", SymbolKind::Property),
+        ]);
+
+        let web = symbols.iter().find(|s| s.name == "web").unwrap();
+        assert_eq!(web.container.as_deref(), Some("aws_instance"));
+        assert_eq!(web.start_line, 9);
+        assert_eq!(web.end_line, 11);
+        assert_eq!(&hcl_code[web.name_range.clone().unwrap()], "web");
+    }
+
+    #[test]
+    fn test_terraform_filtering() {
+        let hcl_code = "resource \"aws_s3_bucket\" \"logs\" {}\nvariable \"env\" {}\n";
+
+        let language = get_language("storage.tf").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut resource_filter = HashSet::new();
+        resource_filter.insert(SymbolKind::Class);
+
+        let symbols = parser.extract_symbols(hcl_code, "storage.tf", Some(resource_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].qualified_name.as_deref(), Some("aws_s3_bucket.logs"));
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::html_visitor::HtmlVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_html_element_extraction() {
+        let html_code = r#"<!DOCTYPE html>
+<html>
+<body>
+  <main id="app">
+    <checkout-button id=buy></checkout-button>
+    <img id="logo" src="logo.png">
+    <p class="note">No id, no symbol</p>
+  </main>
+</body>
+</html>
+"#;
+
+        let visitor = HtmlVisitor::new();
+        let language = get_language("index.html").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(html_code, visitor).unwrap();
+
+        let found: Vec<_> = symbols.iter()
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.kind, s.start_line, s.end_line))
+            .collect();
+        assert_eq!(found, vec![
+            ("main#app", SymbolKind::Element, 4, 8),
+            ("checkout-button#buy", SymbolKind::Element, 5, 5),
+            ("img#logo", SymbolKind::Element, 6, 6),
+        ]);
+        assert_eq!(&html_code[symbols[0].name_range.clone().unwrap()], "app");
+    }
+
+    #[test]
+    fn test_html_embedded_script_and_style() {
+        let html_code = r#"<style>
+  #app { margin: 0; }
+</style>
+<script type="application/json">{"config": true}</script>
+<script type="module">
+  class CheckoutButton extends HTMLElement {}
+  customElements.define('checkout-button', CheckoutButton);
+</script>
+"#;
+
+        let language = get_language("index.html").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.extract_symbols(html_code, "index.html", None).unwrap();
+
+        let found: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.start_line))
+            .collect();
+        assert_eq!(found, vec![
+            ("css", SymbolKind::CodeBlock, 1),
+            ("#app", SymbolKind::Selector, 2),
+            ("js", SymbolKind::CodeBlock, 5),
+            ("CheckoutButton", SymbolKind::Class, 6),
+            ("checkout-button", SymbolKind::Element, 7),
+        ]);
+
+        let element = symbols.iter().find(|s| s.kind == SymbolKind::Element).unwrap();
+        assert_eq!(element.implementation.as_deref(), Some("CheckoutButton"));
+        assert_eq!(element.container, None);
+    }
+
+    #[test]
+    fn test_html_filtering() {
+        let html_code = "<div id=\"root\"></div>\n<script>function boot() {}</script>\n";
+
+        let language = get_language("index.htm").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut element_filter = HashSet::new();
+        element_filter.insert(SymbolKind::Element);
+
+        let symbols = parser.extract_symbols(html_code, "index.htm", Some(element_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "root");
+    }
+}
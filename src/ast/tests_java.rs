@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::java_visitor::JavaVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_java_class_extraction() {
+        let java_code = r#"
+package com.acme.orders;
+
+public class OrderService {
+    private final OrderRepository repository;
+
+    public OrderService(OrderRepository repository) {
+        this.repository = repository;
+    }
+
+    public Order create(String id) {
+        return repository.save(new Order(id));
+    }
+
+    static class Cache {
+        void clear() {}
+    }
+}
+"#;
+
+        let visitor = JavaVisitor::new();
+        let language = get_language("OrderService.java").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(java_code, visitor).unwrap();
+
+        let service = symbols.iter().find(|s| s.name == "OrderService" && s.kind == SymbolKind::Class).unwrap();
+        assert_eq!(service.start_line, 4);
+        assert_eq!(service.end_line, 18);
+        assert_eq!(service.container.as_deref(), Some("com.acme.orders"));
+        assert_eq!(service.qualified_name.as_deref(), Some("com.acme.orders.OrderService"));
+
+        let constructor = symbols.iter().find(|s| s.kind == SymbolKind::Constructor).unwrap();
+        assert_eq!(constructor.name, "OrderService");
+        assert_eq!(constructor.start_line, 7);
+
+        let create = symbols.iter().find(|s| s.name == "create").unwrap();
+        assert_eq!(create.kind, SymbolKind::Method);
+        assert_eq!(create.qualified_name.as_deref(), Some("com.acme.orders.OrderService.create"));
+
+        let repository = symbols.iter().find(|s| s.name == "repository").unwrap();
+        assert_eq!(repository.kind, SymbolKind::Field);
+        assert_eq!(repository.start_line, 5);
+
+        let clear = symbols.iter().find(|s| s.name == "clear").unwrap();
+        assert_eq!(clear.container.as_deref(), Some("com.acme.orders.OrderService.Cache"));
+    }
+
+    #[test]
+    fn test_java_type_declarations() {
+        let java_code = r#"
+package com.acme;
+
+interface Shape {
+    double area();
+    int SIDES = 0;
+}
+
+enum Status {
+    ACTIVE, INACTIVE;
+
+    boolean isActive() { return this == ACTIVE; }
+}
+
+record Point(int x, int y) {
+    Point {
+        if (x < 0) throw new IllegalArgumentException();
+    }
+}
+
+@interface Audited {
+    String value();
+}
+"#;
+
+        let visitor = JavaVisitor::new();
+        let language = get_language("Types.java").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(java_code, visitor).unwrap();
+
+        let shape = symbols.iter().find(|s| s.name == "Shape").unwrap();
+        assert_eq!(shape.kind, SymbolKind::Interface);
+        assert_eq!(shape.start_line, 4);
+
+        let area = symbols.iter().find(|s| s.name == "area").unwrap();
+        assert_eq!(area.kind, SymbolKind::Method);
+        assert!(area.body_range.is_none());
+
+        let sides = symbols.iter().find(|s| s.name == "SIDES").unwrap();
+        assert_eq!(sides.kind, SymbolKind::Field);
+        assert_eq!(sides.qualified_name.as_deref(), Some("com.acme.Shape.SIDES"));
+
+        let status = symbols.iter().find(|s| s.name == "Status").unwrap();
+        assert_eq!(status.kind, SymbolKind::Enum);
+        let is_active = symbols.iter().find(|s| s.name == "isActive").unwrap();
+        assert_eq!(is_active.container.as_deref(), Some("com.acme.Status"));
+
+        let point = symbols.iter().find(|s| s.name == "Point" && s.kind == SymbolKind::Record).unwrap();
+        assert_eq!(point.start_line, 15);
+        assert!(symbols.iter().any(|s| s.name == "Point" && s.kind == SymbolKind::Constructor));
+
+        let audited = symbols.iter().find(|s| s.name == "Audited").unwrap();
+        assert_eq!(audited.kind, SymbolKind::Interface);
+        let value = symbols.iter().find(|s| s.name == "value").unwrap();
+        assert_eq!(value.kind, SymbolKind::Method);
+    }
+
+    #[test]
+    fn test_java_without_package() {
+        let java_code = r#"
+class Main {
+    public static void main(String[] args) {}
+}
+"#;
+
+        let visitor = JavaVisitor::new();
+        let language = get_language("Main.java").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(java_code, visitor).unwrap();
+
+        let main_class = symbols.iter().find(|s| s.name == "Main").unwrap();
+        assert!(main_class.container.is_none());
+        assert_eq!(main_class.qualified_name.as_deref(), Some("Main"));
+
+        let main_method = symbols.iter().find(|s| s.name == "main").unwrap();
+        assert_eq!(main_method.qualified_name.as_deref(), Some("Main.main"));
+    }
+
+    #[test]
+    fn test_java_annotations() {
+        let java_code = r#"
+@RestController
+public class OrderController {
+    @Autowired private OrderService service;
+
+    @GetMapping("/orders")
+    @Deprecated
+    public List<Order> list() { return service.all(); }
+}
+"#;
+
+        let visitor = JavaVisitor::new();
+        let language = get_language("OrderController.java").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(java_code, visitor).unwrap();
+
+        let decorators: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.decorators.clone()))
+            .collect();
+        assert_eq!(decorators, vec![
+            ("OrderController", vec!["RestController".to_string()]),
+            ("service", vec!["Autowired".to_string()]),
+            ("list", vec!["GetMapping(\"/orders\")".to_string(), "Deprecated".to_string()]),
+        ]);
+    }
+
+    #[test]
+    fn test_java_filtering() {
+        let java_code = r#"
+package app;
+
+class Widget {
+    int size;
+    void draw() {}
+    void resize(int size) {}
+}
+"#;
+
+        let language = get_language("Widget.java").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut method_filter = HashSet::new();
+        method_filter.insert(SymbolKind::Method);
+
+        let symbols = parser.extract_symbols(java_code, "Widget.java", Some(method_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 2);
+        assert!(symbols.iter().all(|s| s.kind == SymbolKind::Method));
+        assert!(symbols.iter().any(|s| s.name == "draw"));
+        assert!(symbols.iter().any(|s| s.name == "resize"));
+    }
+}
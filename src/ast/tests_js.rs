@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::{SymbolKind, Visibility, CodeParser, get_language};
     use super::super::js_visitor::JsVisitor;
     use std::collections::HashSet;
 
@@ -103,6 +103,129 @@ var name = "test";
         assert!(variables.iter().any(|v| v.name == "name"));
     }
 
+    #[test]
+    fn test_js_class_fields() {
+        let js_code = r#"
+class Cart {
+    items = [];
+    #secret = 1;
+    static count = 0;
+    add(item) {}
+}
+"#;
+
+        let visitor = JsVisitor::new();
+        let language = get_language("test.js").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(js_code, visitor).unwrap();
+
+        let fields: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Field)
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.start_line))
+            .collect();
+        assert_eq!(fields, vec![("Cart.items", 3), ("Cart.#secret", 4), ("Cart.count", 5)]);
+    }
+
+    #[test]
+    fn test_js_decorators() {
+        let js_code = r#"
+@Component({ selector: "app-cart" })
+class CartComponent {
+    @Input() items = [];
+
+    constructor() {}
+
+    @HostListener("click")
+    onClick() {}
+}
+"#;
+
+        let visitor = JsVisitor::new();
+        let language = get_language("test.js").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(js_code, visitor).unwrap();
+
+        let members: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.decorators.clone()))
+            .collect();
+        assert_eq!(members, vec![
+            ("CartComponent", SymbolKind::Class, vec!["Component({ selector: \"app-cart\" })".to_string()]),
+            ("items", SymbolKind::Field, vec!["Input()".to_string()]),
+            ("constructor", SymbolKind::Constructor, vec![]),
+            ("onClick", SymbolKind::Method, vec!["HostListener(\"click\")".to_string()]),
+        ]);
+        assert!(symbols[3].has_decorator("HostListener"));
+    }
+
+    #[test]
+    fn test_ts_typed_class_members() {
+        let ts_code = r#"
+@Component({ selector: "app-cart" })
+export class CartComponent {
+    @Input() name: string;
+    private count = 0;
+    #secret?: number;
+
+    @HostListener("click")
+    onClick(): void {}
+}
+"#;
+
+        let visitor = JsVisitor::new();
+        let language = get_language("test.ts").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(ts_code, visitor).unwrap();
+
+        // Fields are named after themselves, not their type annotation
+        let members: Vec<_> = symbols.iter()
+            .filter(|s| s.container.is_some())
+            .map(|s| (s.name.as_str(), s.kind, s.start_line, s.visibility, s.decorators.clone()))
+            .collect();
+        assert_eq!(members, vec![
+            ("name", SymbolKind::Field, 4, Some(Visibility::Public), vec!["Input()".to_string()]),
+            ("count", SymbolKind::Field, 5, Some(Visibility::Private), vec![]),
+            ("#secret", SymbolKind::Field, 6, Some(Visibility::Private), vec![]),
+            ("onClick", SymbolKind::Method, 8, Some(Visibility::Public), vec!["HostListener(\"click\")".to_string()]),
+        ]);
+    }
+
+    #[test]
+    fn test_js_visibility() {
+        let js_code = r#"
+export function checkout() {}
+export const TAX = 0.2;
+function total() {}
+const rate = 1;
+export { rate };
+class Cart {
+    #items = [];
+    add() {}
+}
+"#;
+
+        let visitor = JsVisitor::new();
+        let language = get_language("test.js").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(js_code, visitor).unwrap();
+
+        let visibility: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.visibility.unwrap()))
+            .collect();
+        assert_eq!(visibility, vec![
+            ("checkout", Visibility::Exported),
+            ("TAX", Visibility::Exported),
+            ("total", Visibility::Private),
+            ("rate", Visibility::Exported),
+            ("Cart", Visibility::Private),
+            ("#items", Visibility::Private),
+            ("add", Visibility::Public),
+        ]);
+    }
+
     #[test]
     fn test_js_filtering() {
         let js_code = r#"
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::json_visitor::JsonVisitor;
+
+    #[test]
+    fn test_json_key_extraction() {
+        let json_code = r#"{
+  "name": "app",
+  "scripts": {
+    "build": "tsc",
+    "test": "jest"
+  },
+  "files": [
+    { "path": "dist" },
+    "README.md"
+  ]
+}
+"#;
+
+        let visitor = JsonVisitor::new();
+        let language = get_language("package.json").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(json_code, visitor).unwrap();
+
+        assert!(symbols.iter().all(|s| s.kind == SymbolKind::Property));
+
+        let paths: Vec<_> = symbols.iter()
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(paths, vec![
+            "name",
+            "scripts",
+            "scripts.build",
+            "scripts.test",
+            "files",
+            "files[0].path",
+        ]);
+
+        // Objects span their nested keys
+        let scripts = symbols.iter().find(|s| s.name == "scripts").unwrap();
+        assert_eq!(scripts.start_line, 3);
+        assert_eq!(scripts.end_line, 6);
+
+        let build = symbols.iter().find(|s| s.name == "build").unwrap();
+        assert_eq!(build.container.as_deref(), Some("scripts"));
+        assert_eq!(build.start_line, 4);
+    }
+}
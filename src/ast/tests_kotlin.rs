@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::kotlin_visitor::KotlinVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_kotlin_class_extraction() {
+        let kotlin_code = r#"
+package com.acme.orders
+
+class Order(val id: String, var total: Int, note: String) {
+    val label: String = "order"
+
+    fun pay() {
+        val receipt = "local"
+    }
+
+    companion object {
+        fun create(): Order = Order("", 0, "")
+    }
+}
+
+data class Point(val x: Int, val y: Int)
+
+object Registry {
+    var count = 0
+}
+"#;
+
+        let visitor = KotlinVisitor::new();
+        let language = get_language("Order.kt").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(kotlin_code, visitor).unwrap();
+
+        let order = symbols.iter().find(|s| s.name == "Order" && s.kind == SymbolKind::Class).unwrap();
+        assert_eq!(order.start_line, 4);
+        assert_eq!(order.end_line, 14);
+        assert_eq!(order.qualified_name.as_deref(), Some("com.acme.orders.Order"));
+
+        let properties: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Property && s.container.as_deref() == Some("com.acme.orders.Order"))
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(properties, vec!["id", "total", "label"]);
+
+        let pay = symbols.iter().find(|s| s.name == "pay").unwrap();
+        assert_eq!(pay.kind, SymbolKind::Method);
+        assert_eq!(pay.start_line, 7);
+        assert!(!symbols.iter().any(|s| s.name == "receipt"));
+
+        let create = symbols.iter().find(|s| s.name == "create").unwrap();
+        assert_eq!(create.qualified_name.as_deref(), Some("com.acme.orders.Order.Companion.create"));
+
+        let point = symbols.iter().find(|s| s.name == "Point").unwrap();
+        assert_eq!(point.kind, SymbolKind::Class);
+        assert_eq!(point.start_line, 16);
+
+        let registry = symbols.iter().find(|s| s.name == "Registry").unwrap();
+        assert_eq!(registry.kind, SymbolKind::Class);
+        let count = symbols.iter().find(|s| s.name == "count").unwrap();
+        assert_eq!(count.container.as_deref(), Some("com.acme.orders.Registry"));
+    }
+
+    #[test]
+    fn test_kotlin_interfaces_enums_and_functions() {
+        let kotlin_code = r#"
+interface Shape {
+    fun area(): Double
+}
+
+enum class Color { RED, GREEN }
+
+typealias Handler = (String) -> Unit
+
+fun main() {}
+
+val VERSION = "1.0"
+"#;
+
+        let visitor = KotlinVisitor::new();
+        let language = get_language("Shapes.kt").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(kotlin_code, visitor).unwrap();
+
+        let shape = symbols.iter().find(|s| s.name == "Shape").unwrap();
+        assert_eq!(shape.kind, SymbolKind::Interface);
+        let area = symbols.iter().find(|s| s.name == "area").unwrap();
+        assert_eq!(area.kind, SymbolKind::Method);
+
+        let color = symbols.iter().find(|s| s.name == "Color").unwrap();
+        assert_eq!(color.kind, SymbolKind::Enum);
+
+        let handler = symbols.iter().find(|s| s.name == "Handler").unwrap();
+        assert_eq!(handler.kind, SymbolKind::Type);
+
+        let main = symbols.iter().find(|s| s.name == "main").unwrap();
+        assert_eq!(main.kind, SymbolKind::Function);
+        assert!(main.container.is_none());
+
+        let version = symbols.iter().find(|s| s.name == "VERSION").unwrap();
+        assert_eq!(version.kind, SymbolKind::Property);
+        assert_eq!(version.start_line, 12);
+    }
+
+    #[test]
+    fn test_kotlin_extension_functions() {
+        let kotlin_code = r#"
+package com.acme.util
+
+fun String.shout(): String = uppercase()
+
+fun <T> List<T>.second(): T = this[1]
+
+val String?.isBlankOrNull: Boolean get() = this == null
+"#;
+
+        let visitor = KotlinVisitor::new();
+        let language = get_language("Ext.kt").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(kotlin_code, visitor).unwrap();
+
+        let shout = symbols.iter().find(|s| s.name == "shout").unwrap();
+        assert_eq!(shout.kind, SymbolKind::Function);
+        assert_eq!(shout.container.as_deref(), Some("String"));
+        assert_eq!(shout.qualified_name.as_deref(), Some("String.shout"));
+
+        let second = symbols.iter().find(|s| s.name == "second").unwrap();
+        assert_eq!(second.container.as_deref(), Some("List"));
+
+        let is_blank = symbols.iter().find(|s| s.name == "isBlankOrNull").unwrap();
+        assert_eq!(is_blank.kind, SymbolKind::Property);
+        assert_eq!(is_blank.container.as_deref(), Some("String"));
+    }
+
+    #[test]
+    fn test_kotlin_annotations() {
+        let kotlin_code = r#"
+@Serializable
+data class Order(@SerialName("order_id") val id: String) {
+    @Test
+    @get:JvmName("total")
+    fun run() {}
+}
+"#;
+
+        let visitor = KotlinVisitor::new();
+        let language = get_language("Order.kt").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(kotlin_code, visitor).unwrap();
+
+        let order = symbols.iter().find(|s| s.name == "Order").unwrap();
+        assert_eq!(order.decorators, vec!["Serializable"]);
+        let id = symbols.iter().find(|s| s.name == "id").unwrap();
+        assert!(id.has_decorator("SerialName"));
+        let run = symbols.iter().find(|s| s.name == "run").unwrap();
+        assert_eq!(run.decorators, vec!["Test", "JvmName(\"total\")"]);
+    }
+
+    #[test]
+    fn test_kotlin_filtering() {
+        let kotlin_code = r#"
+class Repo {
+    val cache = mutableMapOf<String, Int>()
+    fun load() {}
+    fun save() {}
+}
+"#;
+
+        let language = get_language("Repo.kt").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut method_filter = HashSet::new();
+        method_filter.insert(SymbolKind::Method);
+
+        let symbols = parser.extract_symbols(kotlin_code, "Repo.kt", Some(method_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 2);
+        assert!(symbols.iter().any(|s| s.name == "load"));
+        assert!(symbols.iter().any(|s| s.name == "save"));
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::lua_visitor::LuaVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_lua_function_extraction() {
+        let lua_code = r#"local M = {}
+
+function M.new(name)
+    return setmetatable({ name = name }, M)
+end
+
+function M:greet()
+    print("hi " .. self.name)
+end
+
+function setup() end
+local function helper() end
+"#;
+
+        let visitor = LuaVisitor::new();
+        let language = get_language("module.lua").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(lua_code, visitor).unwrap();
+
+        let new = symbols.iter().find(|s| s.name == "new").unwrap();
+        assert_eq!(new.kind, SymbolKind::Function);
+        assert_eq!(new.container.as_deref(), Some("M"));
+        assert_eq!(new.qualified_name.as_deref(), Some("M.new"));
+        assert_eq!(new.start_line, 3);
+        assert_eq!(new.end_line, 5);
+
+        let greet = symbols.iter().find(|s| s.name == "greet").unwrap();
+        assert_eq!(greet.kind, SymbolKind::Method);
+        assert_eq!(greet.qualified_name.as_deref(), Some("M:greet"));
+
+        let setup = symbols.iter().find(|s| s.name == "setup").unwrap();
+        assert_eq!(setup.container, None);
+        assert!(symbols.iter().any(|s| s.name == "helper"));
+    }
+
+    #[test]
+    fn test_lua_assigned_functions() {
+        let lua_code = r#"
+local M = {}
+local on_exit = function() end
+M.handlers.run = function(cmd) end
+local count = 0
+"#;
+
+        let visitor = LuaVisitor::new();
+        let language = get_language("init.lua").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(lua_code, visitor).unwrap();
+
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[0].name, "on_exit");
+        assert_eq!(symbols[0].start_line, 3);
+
+        assert_eq!(symbols[1].name, "run");
+        assert_eq!(symbols[1].container.as_deref(), Some("M.handlers"));
+        assert_eq!(symbols[1].qualified_name.as_deref(), Some("M.handlers.run"));
+    }
+
+    #[test]
+    fn test_lua_filtering() {
+        let lua_code = r#"
+local Stack = {}
+function Stack.new() end
+function Stack:push(v) end
+"#;
+
+        let language = get_language("stack.lua").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut method_filter = HashSet::new();
+        method_filter.insert(SymbolKind::Method);
+
+        let symbols = parser.extract_symbols(lua_code, "stack.lua", Some(method_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "push");
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::make_visitor::MakeVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_makefile_extraction() {
+        let makefile = r#"include common.mk
+-include local.mk
+
+CC := gcc
+ifeq ($(DEBUG),1)
+CFLAGS = -O0 -g
+endif
+CFLAGS += -Wall
+
+.PHONY: all clean
+
+all: app
+
+app test: main.o
+	$(CC) -o $@ $^
+
+clean:
+	rm -f *.o
+"#;
+
+        let visitor = MakeVisitor::new();
+        let language = get_language("Makefile").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(makefile, visitor).unwrap();
+
+        let found: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.start_line, s.end_line))
+            .collect();
+        assert_eq!(found, vec![
+            ("common.mk", SymbolKind::Import, 1, 1),
+            ("local.mk", SymbolKind::Import, 2, 2),
+            ("CC", SymbolKind::Variable, 4, 4),
+            ("CFLAGS", SymbolKind::Variable, 6, 6),
+            ("all", SymbolKind::Function, 12, 12),
+            ("app", SymbolKind::Function, 14, 15),
+            ("test", SymbolKind::Function, 14, 15),
+            ("clean", SymbolKind::Function, 17, 18),
+        ]);
+    }
+
+    #[test]
+    fn test_makefile_filtering() {
+        let makefile = "VERSION = 1.0\n\nrelease: build\n\ttar czf app-$(VERSION).tgz app\n";
+
+        let mut parser = CodeParser::new(get_language("GNUmakefile").unwrap()).unwrap();
+
+        let mut target_filter = HashSet::new();
+        target_filter.insert(SymbolKind::Function);
+
+        let symbols = parser.extract_symbols(makefile, "GNUmakefile", Some(target_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "release");
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::markdown_visitor::MarkdownVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_markdown_section_extraction() {
+        let markdown = r#"# Guide
+
+Intro text.
+
+## Install
+
+Run the installer.
+
+### From source
+
+Build it.
+
+## Usage
+
+Usage notes
+-----------
+"#;
+
+        let visitor = MarkdownVisitor::new();
+        let language = get_language("README.md").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(markdown, visitor).unwrap();
+
+        let sections: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Section)
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.start_line, s.end_line))
+            .collect();
+        assert_eq!(sections, vec![
+            ("Guide", 1, 16),
+            ("Guide > Install", 5, 11),
+            ("Guide > Install > From source", 9, 11),
+            ("Guide > Usage", 13, 16),
+            ("Guide > Usage > Usage notes", 15, 16),
+        ]);
+
+        let from_source = symbols.iter().find(|s| s.name == "From source").unwrap();
+        assert_eq!(from_source.container.as_deref(), Some("Guide > Install"));
+    }
+
+    #[test]
+    fn test_markdown_code_fences() {
+        let markdown = r#"# API
+
+```python title="example.py"
+class Client:
+    def connect(self):
+        pass
+```
+
+~~~
+plain text
+~~~
+"#;
+
+        let language = get_language("api.md").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.extract_symbols(markdown, "api.md", None).unwrap();
+
+        let blocks: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::CodeBlock)
+            .collect();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].name, "python");
+        assert_eq!(blocks[0].container.as_deref(), Some("API"));
+        assert_eq!(blocks[0].start_line, 3);
+        assert_eq!(blocks[0].end_line, 7);
+        assert_eq!(blocks[1].name, "code");
+
+        // Fence contents are only parsed on request
+        assert!(!symbols.iter().any(|s| s.name == "Client"));
+
+        parser.set_code_fences(true);
+        let symbols = parser.extract_symbols(markdown, "api.md", None).unwrap();
+
+        let client = symbols.iter().find(|s| s.name == "Client").unwrap();
+        assert_eq!(client.kind, SymbolKind::Class);
+        assert_eq!(client.start_line, 4);
+        assert_eq!(client.end_line, 6);
+        assert_eq!(&markdown[client.name_range.clone().unwrap()], "Client");
+
+        let connect = symbols.iter().find(|s| s.name == "connect").unwrap();
+        assert_eq!(connect.start_line, 5);
+    }
+
+    #[test]
+    fn test_markdown_filtering() {
+        let markdown = "# Title\n\n```rust\nfn main() {}\n```\n";
+
+        let language = get_language("notes.markdown").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut section_filter = HashSet::new();
+        section_filter.insert(SymbolKind::Section);
+
+        let symbols = parser.extract_symbols(markdown, "notes.markdown", Some(section_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "Title");
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::notebook;
+    use std::collections::HashSet;
+
+    const NOTEBOOK: &str = r###"{
+ "cells": [
+  {"cell_type": "markdown", "metadata": {}, "source": ["# Analysis\n", "\n", "## Loading"]},
+  {"cell_type": "code", "execution_count": 1, "metadata": {}, "outputs": [],
+   "source": ["%matplotlib inline\n", "import pandas as pd\n", "!pip install pyarrow\n", "def load(path):\n", "    return pd.read_csv(path)"]},
+  {"cell_type": "code", "metadata": {}, "outputs": [], "source": []},
+  {"cell_type": "code", "metadata": {}, "outputs": [], "source": "%%bash\nfunction setup() { echo hi; }\n"},
+  {"cell_type": "code", "metadata": {}, "outputs": [], "source": "class Model:\n    pass\n"}
+ ],
+ "metadata": {},
+ "nbformat": 4,
+ "nbformat_minor": 5
+}"###;
+
+    #[test]
+    fn test_notebook_code_cells() {
+        let symbols = notebook::extract_symbols(NOTEBOOK, false).unwrap();
+
+        let found: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.start_line, s.cell_index, s.cell_line))
+            .collect();
+        // Lines count through all cells; the markdown cell takes lines 1-3
+        // and the `%%bash` cell is not Python
+        assert_eq!(found, vec![
+            ("load", SymbolKind::Function, 7, Some(1), Some(4)),
+            ("Model", SymbolKind::Class, 11, Some(4), Some(1)),
+        ]);
+    }
+
+    #[test]
+    fn test_notebook_markdown_headings() {
+        let symbols = notebook::extract_symbols(NOTEBOOK, true).unwrap();
+
+        let sections: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Section)
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.start_line, s.cell_index, s.cell_line))
+            .collect();
+        assert_eq!(sections, vec![
+            ("Analysis", 1, Some(0), Some(1)),
+            ("Analysis > Loading", 3, Some(0), Some(3)),
+        ]);
+        assert_eq!(symbols.len(), 4);
+
+        assert!(notebook::extract_symbols("{\"cells\": 1}", false).is_err());
+    }
+
+    #[test]
+    fn test_notebook_filtering() {
+        let mut parser = CodeParser::new(get_language("notebook.py").unwrap()).unwrap();
+        parser.set_notebook_headings(true);
+
+        let mut class_filter = HashSet::new();
+        class_filter.insert(SymbolKind::Class);
+
+        let symbols = parser.extract_symbols(NOTEBOOK, "analysis.ipynb", Some(class_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "Model");
+        assert_eq!(symbols[0].cell_index, Some(4));
+    }
+}
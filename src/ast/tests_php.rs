@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::php_visitor::PhpVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_php_class_extraction() {
+        let php_code = r#"<?php
+namespace App\Models;
+
+const VERSION = "2.1";
+
+function format_price(int $cents): string {
+    return number_format($cents / 100, 2);
+}
+
+class Order extends Model implements Payable
+{
+    use Loggable;
+
+    const STATUS_OPEN = 'open', STATUS_PAID = 'paid';
+
+    public static ?int $count = 0, $limit;
+
+    public function __construct(private string $id) {}
+
+    public function pay(): void {}
+}
+"#;
+
+        let visitor = PhpVisitor::new();
+        let language = get_language("Order.php").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(php_code, visitor).unwrap();
+
+        let namespace = symbols.iter().find(|s| s.kind == SymbolKind::Namespace).unwrap();
+        assert_eq!(namespace.name, "App\\Models");
+        assert_eq!(namespace.end_line, 22);
+
+        let version = symbols.iter().find(|s| s.name == "VERSION").unwrap();
+        assert_eq!(version.kind, SymbolKind::Constant);
+        assert_eq!(version.qualified_name.as_deref(), Some("App\\Models\\VERSION"));
+
+        let format_price = symbols.iter().find(|s| s.name == "format_price").unwrap();
+        assert_eq!(format_price.kind, SymbolKind::Function);
+        assert_eq!(format_price.start_line, 6);
+
+        let order = symbols.iter().find(|s| s.name == "Order").unwrap();
+        assert_eq!(order.kind, SymbolKind::Class);
+        assert_eq!(order.start_line, 10);
+        assert_eq!(order.qualified_name.as_deref(), Some("App\\Models\\Order"));
+
+        let constants: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Constant && s.container.as_deref() == Some("App\\Models\\Order"))
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(constants, vec!["STATUS_OPEN", "STATUS_PAID"]);
+
+        let properties: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Property)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(properties, vec!["$count", "$limit", "$id"]);
+
+        let constructor = symbols.iter().find(|s| s.kind == SymbolKind::Constructor).unwrap();
+        assert_eq!(constructor.name, "__construct");
+
+        let pay = symbols.iter().find(|s| s.name == "pay").unwrap();
+        assert_eq!(pay.kind, SymbolKind::Method);
+        assert_eq!(pay.qualified_name.as_deref(), Some("App\\Models\\Order::pay"));
+    }
+
+    #[test]
+    fn test_php_interfaces_traits_enums() {
+        let php_code = r#"<?php
+interface Payable
+{
+    public function pay(): void;
+}
+
+trait Loggable
+{
+    protected array $log = [];
+}
+
+enum Status: string
+{
+    case Open = 'open';
+
+    public function label(): string { return 'x'; }
+}
+"#;
+
+        let visitor = PhpVisitor::new();
+        let language = get_language("types.php").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(php_code, visitor).unwrap();
+
+        let payable = symbols.iter().find(|s| s.name == "Payable").unwrap();
+        assert_eq!(payable.kind, SymbolKind::Interface);
+        assert_eq!(payable.start_line, 2);
+
+        let loggable = symbols.iter().find(|s| s.name == "Loggable").unwrap();
+        assert_eq!(loggable.kind, SymbolKind::Trait);
+        let log = symbols.iter().find(|s| s.name == "$log").unwrap();
+        assert_eq!(log.qualified_name.as_deref(), Some("Loggable::$log"));
+
+        let status = symbols.iter().find(|s| s.name == "Status").unwrap();
+        assert_eq!(status.kind, SymbolKind::Enum);
+        let label = symbols.iter().find(|s| s.name == "label").unwrap();
+        assert_eq!(label.container.as_deref(), Some("Status"));
+    }
+
+    #[test]
+    fn test_php_interleaved_html() {
+        let php_code = r#"<html>
+<body>
+<?php
+function render_header($title) {
+    echo "<h1>$title</h1>";
+}
+?>
+<p><?= render_header("Home") ?></p>
+<?php namespace Views { function footer() {} } ?>
+</body>
+</html>
+"#;
+
+        let language = get_language("page.phtml").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.extract_symbols(php_code, "page.phtml", None).unwrap();
+
+        let header = symbols.iter().find(|s| s.name == "render_header").unwrap();
+        assert_eq!(header.kind, SymbolKind::Function);
+        assert_eq!(header.start_line, 4);
+        assert_eq!(header.end_line, 6);
+
+        let footer = symbols.iter().find(|s| s.name == "footer").unwrap();
+        assert_eq!(footer.start_line, 9);
+        assert_eq!(footer.qualified_name.as_deref(), Some("Views\\footer"));
+
+        assert!(get_language("legacy.inc").is_some());
+    }
+
+    #[test]
+    fn test_php_filtering() {
+        let php_code = r#"<?php
+class Cart
+{
+    public $items = [];
+    public function add($item) {}
+    public function clear() {}
+}
+"#;
+
+        let language = get_language("Cart.php").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut method_filter = HashSet::new();
+        method_filter.insert(SymbolKind::Method);
+
+        let symbols = parser.extract_symbols(php_code, "Cart.php", Some(method_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 2);
+        assert!(symbols.iter().any(|s| s.name == "add"));
+        assert!(symbols.iter().any(|s| s.name == "clear"));
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::proto_visitor::ProtoVisitor;
+
+    #[test]
+    fn test_proto_message_extraction() {
+        let proto_code = r#"syntax = "proto3";
+package shop.v1;
+
+import "google/protobuf/timestamp.proto";
+
+message Order {
+  string id = 1;
+  message Item {
+    string sku = 1;
+  }
+  enum Status {
+    UNKNOWN = 0;
+    PAID = 1;
+  }
+  repeated Item items = 2;
+}
+"#;
+
+        let visitor = ProtoVisitor::new();
+        let language = get_language("order.proto").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(proto_code, visitor).unwrap();
+
+        let package = symbols.iter().find(|s| s.kind == SymbolKind::Namespace).unwrap();
+        assert_eq!(package.name, "shop.v1");
+        assert_eq!(package.start_line, 2);
+
+        let import = symbols.iter().find(|s| s.kind == SymbolKind::Import).unwrap();
+        assert_eq!(import.name, "google/protobuf/timestamp.proto");
+
+        let order = symbols.iter().find(|s| s.name == "Order").unwrap();
+        assert_eq!(order.kind, SymbolKind::Struct);
+        assert_eq!(order.qualified_name.as_deref(), Some("shop.v1.Order"));
+        assert_eq!(order.start_line, 6);
+        assert_eq!(order.end_line, 16);
+
+        let item = symbols.iter().find(|s| s.name == "Item").unwrap();
+        assert_eq!(item.container.as_deref(), Some("shop.v1.Order"));
+        assert_eq!(item.start_line, 8);
+
+        let status = symbols.iter().find(|s| s.name == "Status").unwrap();
+        assert_eq!(status.kind, SymbolKind::Enum);
+        assert_eq!(status.qualified_name.as_deref(), Some("shop.v1.Order.Status"));
+
+        // Fields are not definitions of their own
+        assert!(!symbols.iter().any(|s| s.name == "sku" || s.name == "UNKNOWN"));
+    }
+
+    #[test]
+    fn test_proto_service_extraction() {
+        let proto_code = r#"syntax = "proto3";
+
+service OrderService {
+  rpc CreateOrder(CreateOrderRequest) returns (Order);
+  rpc Watch(stream WatchRequest) returns (stream Order) {
+    option deprecated = true;
+  }
+}
+"#;
+
+        let language = get_language("service.proto").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.extract_symbols(proto_code, "service.proto", None).unwrap();
+
+        let service = symbols.iter().find(|s| s.name == "OrderService").unwrap();
+        assert_eq!(service.kind, SymbolKind::Interface);
+        assert_eq!(service.container, None);
+
+        let rpcs: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Method)
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.start_line, s.end_line))
+            .collect();
+        assert_eq!(rpcs, vec![
+            ("OrderService.CreateOrder", 4, 4),
+            ("OrderService.Watch", 5, 7),
+        ]);
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use super::super::{CodeParser, get_language};
-    use super::super::symbol::SymbolKind;
+    use super::super::symbol::{SymbolKind, Visibility};
     use super::super::python_visitor::PythonVisitor;
     use std::collections::HashSet;
 
@@ -105,6 +105,270 @@ def another_function():
         assert_eq!(classes.len(), 1); // TestClass
     }
 
+    #[test]
+    fn test_python_type_extraction() {
+        let python_code = r#"
+import enum
+from typing import Protocol, TypeAlias, TypedDict
+
+class Color(enum.Enum):
+    RED = 1
+
+class Status(StrEnum):
+    OK = "ok"
+
+class Closeable(Protocol[T]):
+    def close(self) -> None: ...
+
+class Movie(TypedDict, total=False):
+    title: str
+
+class Plain(Base):
+    pass
+
+Vector: TypeAlias = list[float]
+count: int = 0
+type Pair[T] = tuple[T, T]
+"#;
+
+        let visitor = PythonVisitor::new();
+        let language = get_language("test.py").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(python_code, visitor).unwrap();
+
+        let kinds: Vec<_> = symbols.iter()
+            .filter(|s| s.kind != SymbolKind::Function && s.container.is_none())
+            .map(|s| (s.name.as_str(), s.kind))
+            .collect();
+        assert_eq!(kinds, vec![
+            ("Color", SymbolKind::Enum),
+            ("Status", SymbolKind::Enum),
+            ("Closeable", SymbolKind::Interface),
+            ("Movie", SymbolKind::Struct),
+            ("Plain", SymbolKind::Class),
+            ("Vector", SymbolKind::Type),
+            ("count", SymbolKind::Variable),
+            ("Pair", SymbolKind::Type),
+        ]);
+
+        let pair = symbols.iter().find(|s| s.name == "Pair").unwrap();
+        assert_eq!(pair.start_line, 22);
+        assert_eq!(&python_code[pair.name_range.clone().unwrap()], "Pair");
+    }
+
+    #[test]
+    fn test_python_class_attributes() {
+        let python_code = r#"
+class Color(Enum):
+    _ignore_ = ["x"]
+    RED = 1
+
+class Order:
+    id: int
+    total = 0
+
+    def __init__(self, ref):
+        self.ref = ref
+        if ref:
+            self.items = []
+        self.ref = None
+
+        def helper(other):
+            self.hidden = 1
+
+    def pay(self):
+        self.paid = True
+"#;
+
+        let visitor = PythonVisitor::new();
+        let language = get_language("test.py").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(python_code, visitor).unwrap();
+
+        let members: Vec<_> = symbols.iter()
+            .filter(|s| s.container.is_some())
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.kind, s.start_line))
+            .collect();
+        assert_eq!(members, vec![
+            ("Color.RED", SymbolKind::EnumMember, 4),
+            ("Order.id", SymbolKind::Field, 7),
+            ("Order.ref", SymbolKind::Property, 11),
+            ("Order.items", SymbolKind::Property, 13),
+            ("Order.__init__", SymbolKind::Function, 10),
+            ("Order.pay", SymbolKind::Function, 19),
+        ]);
+    }
+
+    #[test]
+    fn test_python_nested_classes() {
+        let python_code = r#"
+class Order:
+    class Inner:
+        size: int
+
+        def m(self):
+            pass
+
+    def pay(self):
+        pass
+"#;
+
+        let visitor = PythonVisitor::new();
+        let language = get_language("test.py").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(python_code, visitor).unwrap();
+
+        let names: Vec<_> = symbols.iter()
+            .map(|s| (s.qualified_name.as_deref().unwrap_or(&s.name), s.container.as_deref(), s.kind))
+            .collect();
+        assert_eq!(names, vec![
+            ("Order", None, SymbolKind::Class),
+            ("Order.Inner", Some("Order"), SymbolKind::Class),
+            ("Order.Inner.size", Some("Order.Inner"), SymbolKind::Field),
+            ("Order.Inner.m", Some("Order.Inner"), SymbolKind::Function),
+            ("Order.pay", Some("Order"), SymbolKind::Function),
+        ]);
+    }
+
+    #[test]
+    fn test_python_module_variables() {
+        let python_code = r#"
+__all__ = ["Order", 'load']
+MAX_RETRIES = 3
+timeout: Final = 30
+name: str
+a, (b, *c) = 1, (2, 3)
+x = y = 2
+x = 5
+
+class Order:
+    total = 0
+
+def load():
+    data = []
+    return data
+"#;
+
+        let language = get_language("test.py").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(python_code, PythonVisitor::new()).unwrap();
+
+        let variables: Vec<_> = symbols.iter()
+            .filter(|s| !matches!(s.kind, SymbolKind::Class | SymbolKind::Function))
+            .map(|s| (s.name.as_str(), s.kind, s.start_line))
+            .collect();
+        assert_eq!(variables, vec![
+            ("__all__", SymbolKind::Variable, 2),
+            ("Order", SymbolKind::Export, 2),
+            ("load", SymbolKind::Export, 2),
+            ("MAX_RETRIES", SymbolKind::Constant, 3),
+            ("timeout", SymbolKind::Constant, 4),
+            ("name", SymbolKind::Variable, 5),
+            ("a", SymbolKind::Variable, 6),
+            ("b", SymbolKind::Variable, 6),
+            ("c", SymbolKind::Variable, 6),
+            ("x", SymbolKind::Variable, 7),
+            ("y", SymbolKind::Variable, 7),
+        ]);
+
+        // Locals are only reported on request, contained in their function
+        let symbols = parser.parse_with_visitor(python_code, PythonVisitor::new().with_locals(true)).unwrap();
+        let data = symbols.iter().find(|s| s.name == "data").unwrap();
+        assert_eq!(data.qualified_name.as_deref(), Some("load.data"));
+        assert!(!symbols.iter().any(|s| s.name == "total"));
+    }
+
+    #[test]
+    fn test_python_decorators() {
+        let python_code = r#"
+@pytest.fixture(scope="module")
+def client():
+    pass
+
+@dataclass
+@total_ordering
+class Order:
+    @app.get("/orders")
+    async def list_orders(self):
+        pass
+"#;
+
+        let visitor = PythonVisitor::new();
+        let language = get_language("test.py").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(python_code, visitor).unwrap();
+
+        let client = symbols.iter().find(|s| s.name == "client").unwrap();
+        assert_eq!(client.decorators, vec!["pytest.fixture(scope=\"module\")"]);
+        assert_eq!((client.start_line, client.end_line), (2, 4));
+        assert!(client.has_decorator("@pytest.fixture"));
+        assert!(!client.has_decorator("pytest"));
+
+        let order = symbols.iter().find(|s| s.name == "Order").unwrap();
+        assert_eq!(order.decorators, vec!["dataclass", "total_ordering"]);
+        assert_eq!(order.start_line, 6);
+        assert_eq!(&python_code[order.full_range.clone()][..10], "@dataclass");
+
+        let list_orders = symbols.iter().find(|s| s.name == "list_orders").unwrap();
+        assert!(list_orders.has_decorator("app.get"));
+        assert_eq!(list_orders.start_line, 9);
+    }
+
+    #[test]
+    fn test_python_visibility() {
+        let python_code = r#"
+class Order:
+    _cache: dict
+
+    def __init__(self):
+        self.id = 1
+
+    def _validate(self):
+        pass
+
+def load():
+    def helper():
+        pass
+
+def _connect():
+    pass
+"#;
+
+        let language = get_language("test.py").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(python_code, PythonVisitor::new()).unwrap();
+        let visibility: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.visibility.unwrap()))
+            .collect();
+        assert_eq!(visibility, vec![
+            ("Order", Visibility::Public),
+            ("_cache", Visibility::Private),
+            ("id", Visibility::Public),
+            ("__init__", Visibility::Public),
+            ("_validate", Visibility::Private),
+            ("load", Visibility::Public),
+            ("helper", Visibility::Private),
+            ("_connect", Visibility::Private),
+        ]);
+
+        // `__all__` decides what the module exports
+        let python_code = format!("__all__ = ['_connect']\n{}", python_code);
+        let symbols = parser.parse_with_visitor(&python_code, PythonVisitor::new()).unwrap();
+        let exported: Vec<_> = symbols.iter()
+            .filter(|s| s.visibility == Some(Visibility::Exported))
+            .map(|s| (s.name.as_str(), s.kind))
+            .collect();
+        assert_eq!(exported, vec![("_connect", SymbolKind::Export), ("_connect", SymbolKind::Function)]);
+        let order = symbols.iter().find(|s| s.name == "Order").unwrap();
+        assert_eq!(order.visibility, Some(Visibility::Private));
+    }
+
     #[test]
     fn test_python_filtering() {
         let python_code = r#"
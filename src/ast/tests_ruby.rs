@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::ruby_visitor::RubyVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_ruby_module_and_class_extraction() {
+        let ruby_code = r#"
+module Shop::Billing
+  class Invoice
+    TAX_RATE = 0.2
+
+    def self.build(order)
+      new(order)
+    end
+
+    def total
+      42
+    end
+
+    class << self
+      def default
+      end
+    end
+  end
+end
+"#;
+
+        let visitor = RubyVisitor::new();
+        let language = get_language("invoice.rb").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(ruby_code, visitor).unwrap();
+
+        let billing = symbols.iter().find(|s| s.kind == SymbolKind::Module).unwrap();
+        assert_eq!(billing.name, "Shop::Billing");
+        assert_eq!(billing.start_line, 2);
+        assert_eq!(billing.end_line, 19);
+
+        let invoice = symbols.iter().find(|s| s.kind == SymbolKind::Class).unwrap();
+        assert_eq!(invoice.qualified_name.as_deref(), Some("Shop::Billing::Invoice"));
+
+        let tax_rate = symbols.iter().find(|s| s.name == "TAX_RATE").unwrap();
+        assert_eq!(tax_rate.kind, SymbolKind::Constant);
+        assert_eq!(tax_rate.qualified_name.as_deref(), Some("Shop::Billing::Invoice::TAX_RATE"));
+
+        let build = symbols.iter().find(|s| s.name == "build").unwrap();
+        assert_eq!(build.kind, SymbolKind::Method);
+        assert_eq!(build.start_line, 6);
+        assert_eq!(build.qualified_name.as_deref(), Some("Shop::Billing::Invoice.build"));
+
+        let total = symbols.iter().find(|s| s.name == "total").unwrap();
+        assert_eq!(total.qualified_name.as_deref(), Some("Shop::Billing::Invoice#total"));
+
+        let default = symbols.iter().find(|s| s.name == "default").unwrap();
+        assert_eq!(default.qualified_name.as_deref(), Some("Shop::Billing::Invoice.default"));
+    }
+
+    #[test]
+    fn test_ruby_rails_dsl() {
+        let ruby_code = r#"
+class Order < ApplicationRecord
+  attr_accessor :note, :gift
+  has_many :line_items, dependent: :destroy
+  belongs_to :customer
+  scope :recent, -> { where(created_at: 1.week.ago..) }
+
+  def paid?
+    true
+  end
+end
+"#;
+
+        let visitor = RubyVisitor::new();
+        let language = get_language("order.rb").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(ruby_code, visitor).unwrap();
+
+        let properties: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Property)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(properties, vec!["note", "gift", "line_items", "customer"]);
+
+        let note = symbols.iter().find(|s| s.name == "note").unwrap();
+        assert_eq!(note.start_line, 3);
+        assert_eq!(note.qualified_name.as_deref(), Some("Order#note"));
+
+        let recent = symbols.iter().find(|s| s.name == "recent").unwrap();
+        assert_eq!(recent.kind, SymbolKind::Method);
+        assert_eq!(recent.start_line, 6);
+        assert_eq!(recent.qualified_name.as_deref(), Some("Order.recent"));
+
+        assert!(!symbols.iter().any(|s| s.name == "where"));
+    }
+
+    #[test]
+    fn test_ruby_rspec_blocks() {
+        let ruby_code = r#"
+RSpec.describe Order do
+  context "when paid" do
+    it "sends a receipt" do
+      expect(order.receipt).to be_present
+    end
+  end
+
+  it "has items" do
+  end
+end
+"#;
+
+        let visitor = RubyVisitor::new();
+        let language = get_language("order_spec.rb").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(ruby_code, visitor).unwrap();
+
+        let tests: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Test)
+            .collect();
+        assert_eq!(tests.len(), 4);
+
+        let describe = tests.iter().find(|s| s.name == "Order").unwrap();
+        assert_eq!(describe.start_line, 2);
+        assert_eq!(describe.end_line, 11);
+
+        let receipt = tests.iter().find(|s| s.name == "sends a receipt").unwrap();
+        assert_eq!(receipt.start_line, 4);
+        assert_eq!(receipt.container.as_deref(), Some("Order when paid"));
+        assert_eq!(receipt.qualified_name.as_deref(), Some("Order when paid sends a receipt"));
+
+        let items = tests.iter().find(|s| s.name == "has items").unwrap();
+        assert_eq!(items.qualified_name.as_deref(), Some("Order has items"));
+    }
+
+    #[test]
+    fn test_ruby_filtering() {
+        let ruby_code = r#"
+module Util
+  VERSION = "1.0"
+
+  def self.helper; end
+end
+"#;
+
+        let language = get_language("util.rb").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut constant_filter = HashSet::new();
+        constant_filter.insert(SymbolKind::Constant);
+
+        let symbols = parser.extract_symbols(ruby_code, "util.rb", Some(constant_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "VERSION");
+    }
+}
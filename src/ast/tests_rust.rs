@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, Visibility, CodeParser, get_language};
+    use super::super::rust_visitor::RustVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_rust_item_extraction() {
+        let rust_code = r#"
+pub mod orders {
+    pub struct Order {
+        pub id: u64,
+        total: f64,
+    }
+
+    pub enum Status { Open, Closed }
+
+    pub trait Priced {
+        fn price(&self) -> f64;
+    }
+
+    impl<T> Priced for Order {
+        fn price(&self) -> f64 {
+            fn helper() {}
+            self.total
+        }
+    }
+
+    pub const MAX_ITEMS: usize = 10;
+    pub type Id = u64;
+}
+
+macro_rules! order {
+    () => {};
+}
+
+fn main() {}
+"#;
+
+        let visitor = RustVisitor::new();
+        let language = get_language("lib.rs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(rust_code, visitor).unwrap();
+
+        let items: Vec<_> = symbols.iter()
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.kind))
+            .collect();
+        assert_eq!(items, vec![
+            ("orders", SymbolKind::Module),
+            ("orders::Order", SymbolKind::Struct),
+            ("orders::Order::id", SymbolKind::Field),
+            ("orders::Order::total", SymbolKind::Field),
+            ("orders::Status", SymbolKind::Enum),
+            ("orders::Status::Open", SymbolKind::EnumMember),
+            ("orders::Status::Closed", SymbolKind::EnumMember),
+            ("orders::Priced", SymbolKind::Trait),
+            ("orders::Priced::price", SymbolKind::Method),
+            ("orders::Order", SymbolKind::Impl),
+            ("orders::Order::price", SymbolKind::Method),
+            ("orders::MAX_ITEMS", SymbolKind::Constant),
+            ("orders::Id", SymbolKind::Type),
+            ("order", SymbolKind::Macro),
+            ("main", SymbolKind::Function),
+        ]);
+
+        let order = &symbols[1];
+        assert_eq!((order.start_line, order.end_line), (3, 6));
+        assert_eq!(order.container.as_deref(), Some("orders"));
+    }
+
+    #[test]
+    fn test_rust_attributes() {
+        let rust_code = r#"
+#[derive(Debug, Clone)]
+/// An order.
+pub struct Order;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn creates_order() {}
+
+    #[tokio::test]
+    async fn sends_order() {}
+}
+"#;
+
+        let visitor = RustVisitor::new();
+        let language = get_language("lib.rs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(rust_code, visitor).unwrap();
+
+        let order = symbols.iter().find(|s| s.name == "Order").unwrap();
+        assert_eq!(order.decorators, vec!["derive(Debug, Clone)"]);
+        assert_eq!(order.start_line, 2);
+        assert!(order.has_decorator("derive"));
+
+        let tests: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Test)
+            .map(|s| (s.name.as_str(), s.start_line))
+            .collect();
+        assert_eq!(tests, vec![("creates_order", 8), ("sends_order", 11)]);
+        assert!(symbols.iter().find(|s| s.name == "creates_order").unwrap().has_decorator("#[test]"));
+        assert!(!symbols.iter().find(|s| s.name == "sends_order").unwrap().has_decorator("test"));
+    }
+
+    #[test]
+    fn test_rust_visibility() {
+        let rust_code = r#"
+pub struct Order {
+    pub id: u64,
+    total: f64,
+}
+
+impl Order {
+    pub fn new() -> Self { todo!() }
+    fn recalculate(&mut self) {}
+}
+
+impl Display for Order {
+    fn fmt(&self, f: &mut Formatter) -> Result { todo!() }
+}
+
+mod internal {
+    pub fn helper() {}
+}
+
+pub(crate) fn shared() {}
+"#;
+
+        let language = get_language("lib.rs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.extract_symbols(rust_code, "lib.rs", None).unwrap();
+        let visibility: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.visibility))
+            .collect();
+        assert_eq!(visibility, vec![
+            ("Order", Some(Visibility::Public)),
+            ("id", Some(Visibility::Public)),
+            ("total", Some(Visibility::Private)),
+            ("Order", None),
+            ("new", Some(Visibility::Public)),
+            ("recalculate", Some(Visibility::Private)),
+            ("Order", None),
+            ("fmt", Some(Visibility::Public)),
+            ("internal", Some(Visibility::Private)),
+            ("helper", Some(Visibility::Public)),
+            ("shared", Some(Visibility::Internal)),
+        ]);
+
+        // `helper` is public, but in a private module
+        parser.set_public_only(true);
+        let symbols = parser.extract_symbols(rust_code, "lib.rs", None).unwrap();
+        let public: Vec<_> = symbols.iter()
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(public, vec!["Order", "Order::id", "Order", "Order::new", "Order", "Order::fmt"]);
+    }
+
+    #[test]
+    fn test_rust_filtering() {
+        let rust_code = r#"
+struct Config;
+fn load() -> Config { Config }
+static VERSION: &str = "1";
+"#;
+
+        let language = get_language("lib.rs").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut filter = HashSet::new();
+        filter.insert(SymbolKind::Function);
+        let symbols = parser.extract_symbols(rust_code, "lib.rs", Some(filter)).unwrap();
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "load");
+    }
+}
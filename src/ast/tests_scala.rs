@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::scala_visitor::ScalaVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_scala_class_extraction() {
+        let scala_code = r#"package com.example.shapes
+
+object Geometry {
+  val Pi = 3.14
+  def area(r: Double): Double = Pi * r * r
+
+  case class Point(x: Int, y: Int) {
+    def norm: Double = math.sqrt(x * x + y * y)
+  }
+}
+
+trait Shape {
+  def area: Double
+}
+
+class Circle(val r: Double) extends Shape {
+  def area = 3.0
+  type Radius = Double
+}
+
+enum Color { case Red, Green }
+"#;
+
+        let visitor = ScalaVisitor::new();
+        let language = get_language("Geometry.scala").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(scala_code, visitor).unwrap();
+
+        let geometry = symbols.iter().find(|s| s.name == "Geometry").unwrap();
+        assert_eq!(geometry.kind, SymbolKind::Class);
+        assert_eq!(geometry.container.as_deref(), Some("com.example.shapes"));
+        assert_eq!(geometry.start_line, 3);
+        assert_eq!(geometry.end_line, 10);
+
+        let point = symbols.iter().find(|s| s.name == "Point").unwrap();
+        assert_eq!(point.kind, SymbolKind::Record);
+        assert_eq!(point.qualified_name.as_deref(), Some("com.example.shapes.Geometry.Point"));
+
+        let norm = symbols.iter().find(|s| s.name == "norm").unwrap();
+        assert_eq!(norm.kind, SymbolKind::Method);
+        assert_eq!(norm.container.as_deref(), Some("com.example.shapes.Geometry.Point"));
+
+        let pi = symbols.iter().find(|s| s.name == "Pi").unwrap();
+        assert_eq!(pi.kind, SymbolKind::Property);
+
+        assert_eq!(symbols.iter().find(|s| s.name == "Shape").unwrap().kind, SymbolKind::Trait);
+        assert_eq!(symbols.iter().find(|s| s.name == "Color").unwrap().kind, SymbolKind::Enum);
+        assert_eq!(symbols.iter().find(|s| s.name == "Radius").unwrap().kind, SymbolKind::Type);
+
+        // Abstract and concrete `area` members, plus the object's function
+        let areas: Vec<_> = symbols.iter()
+            .filter(|s| s.name == "area")
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(areas, vec![
+            "com.example.shapes.Geometry.area",
+            "com.example.shapes.Shape.area",
+            "com.example.shapes.Circle.area",
+        ]);
+
+        // `val` class parameters are properties
+        let r = symbols.iter().find(|s| s.name == "r" && s.kind == SymbolKind::Property).unwrap();
+        assert_eq!(r.container.as_deref(), Some("com.example.shapes.Circle"));
+        assert!(!symbols.iter().any(|s| s.name == "x"));
+    }
+
+    #[test]
+    fn test_scala_top_level_definitions() {
+        let scala_code = r#"package a.b
+package c
+
+package inner {
+  case object Empty
+}
+
+def run(): Unit =
+  val local = 1
+  println(local)
+
+val version = "1.0"
+
+given intOrd: Ordering[Int] = Ordering.Int
+given Ordering[String] = Ordering.String
+
+extension (s: String)
+  def shout: String = s.toUpperCase
+"#;
+
+        let visitor = ScalaVisitor::new();
+        let language = get_language("main.scala").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(scala_code, visitor).unwrap();
+
+        let empty = symbols.iter().find(|s| s.name == "Empty").unwrap();
+        assert_eq!(empty.qualified_name.as_deref(), Some("a.b.c.inner.Empty"));
+
+        let run = symbols.iter().find(|s| s.name == "run").unwrap();
+        assert_eq!(run.kind, SymbolKind::Function);
+        assert_eq!(run.qualified_name.as_deref(), Some("a.b.c.run"));
+        assert!(!symbols.iter().any(|s| s.name == "local"));
+
+        assert_eq!(symbols.iter().find(|s| s.name == "version").unwrap().kind, SymbolKind::Variable);
+
+        let givens: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Impl)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(givens, vec!["intOrd", "Ordering[String]"]);
+
+        // Extension methods are scoped to their receiver
+        let shout = symbols.iter().find(|s| s.name == "shout").unwrap();
+        assert_eq!(shout.kind, SymbolKind::Function);
+        assert_eq!(shout.qualified_name.as_deref(), Some("String.shout"));
+    }
+
+    #[test]
+    fn test_scala_filtering() {
+        let scala_code = r#"
+trait Repo { def find(id: Long): Option[String] }
+object Main { def main(args: Array[String]): Unit = () }
+"#;
+
+        let language = get_language("Main.scala").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut trait_filter = HashSet::new();
+        trait_filter.insert(SymbolKind::Trait);
+
+        let symbols = parser.extract_symbols(scala_code, "Main.scala", Some(trait_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "Repo");
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::sql_visitor::SqlVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_sql_table_extraction() {
+        let sql_code = r#"CREATE TABLE IF NOT EXISTS public.orders (
+  id BIGSERIAL PRIMARY KEY,
+  customer_id INT NOT NULL REFERENCES customers(id),
+  total NUMERIC(10, 2),
+  CONSTRAINT positive CHECK (total > 0)
+);
+
+CREATE TABLE `items` (`id` int(11) NOT NULL, PRIMARY KEY (`id`)) ENGINE=InnoDB;
+
+ALTER TABLE public.orders ADD COLUMN status TEXT, DROP COLUMN total;
+"#;
+
+        let visitor = SqlVisitor::new();
+        let language = get_language("001_orders.sql").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(sql_code, visitor).unwrap();
+
+        let orders: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Table && s.name == "orders")
+            .collect();
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].container.as_deref(), Some("public"));
+        assert_eq!(orders[0].start_line, 1);
+        assert_eq!(orders[0].end_line, 6);
+        assert!(!orders[0].alteration);
+
+        // `ALTER TABLE` is reported as an alteration of the same table
+        assert_eq!(orders[1].qualified_name.as_deref(), Some("public.orders"));
+        assert_eq!(orders[1].start_line, 10);
+        assert!(orders[1].alteration);
+        assert!(!orders[1].partial);
+
+        let columns: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Field)
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(columns, vec![
+            "public.orders.id",
+            "public.orders.customer_id",
+            "public.orders.total",
+            "items.id",
+            "public.orders.status",
+        ]);
+
+        let customer_id = symbols.iter().find(|s| s.name == "customer_id").unwrap();
+        assert_eq!(customer_id.container.as_deref(), Some("public.orders"));
+        assert_eq!(customer_id.start_line, 3);
+    }
+
+    #[test]
+    fn test_sql_views_indexes_and_routines() {
+        let sql_code = r#"CREATE VIEW paid_orders AS SELECT * FROM orders WHERE total > 0;
+
+CREATE MATERIALIZED VIEW daily_totals AS SELECT 1;
+
+CREATE UNIQUE INDEX idx_orders_customer ON orders (customer_id);
+CREATE INDEX ON orders (created_at);
+
+CREATE OR REPLACE FUNCTION order_total(order_id integer) RETURNS numeric AS $$
+  SELECT total FROM orders WHERE id = order_id;
+$$ LANGUAGE sql;
+
+CREATE PROCEDURE archive_orders()
+LANGUAGE plpgsql AS $$ BEGIN DELETE FROM orders; END $$;
+"#;
+
+        let visitor = SqlVisitor::new();
+        let language = get_language("schema.sql").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(sql_code, visitor).unwrap();
+
+        let kinds: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.start_line, s.end_line))
+            .collect();
+        assert_eq!(kinds, vec![
+            ("paid_orders", SymbolKind::View, 1, 1),
+            ("daily_totals", SymbolKind::View, 3, 3),
+            ("idx_orders_customer", SymbolKind::Index, 5, 5),
+            ("order_total", SymbolKind::Function, 8, 10),
+            ("archive_orders", SymbolKind::Function, 12, 13),
+        ]);
+
+        let index = symbols.iter().find(|s| s.kind == SymbolKind::Index).unwrap();
+        assert_eq!(index.container.as_deref(), Some("orders"));
+
+        let archive = symbols.iter().find(|s| s.name == "archive_orders").unwrap();
+        assert_eq!(&sql_code[archive.name_range.clone().unwrap()], "archive_orders");
+    }
+
+    #[test]
+    fn test_sql_filtering() {
+        let sql_code = "CREATE TABLE users (id INT);\nCREATE VIEW active AS SELECT * FROM users;\n";
+
+        let language = get_language("init.sql").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut table_filter = HashSet::new();
+        table_filter.insert(SymbolKind::Table);
+
+        let symbols = parser.extract_symbols(sql_code, "init.sql", Some(table_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "users");
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::stylesheet_scanner;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_scss_extraction() {
+        let scss_code = r#"$primary: #333;
+@use "sass:math";
+
+// Buttons
+@mixin button-size($height) {
+  height: $height;
+}
+
+@function double($n) {
+  @return $n * 2;
+}
+
+.checkout-button,
+#buy {
+  color: $primary;
+  &:hover { color: red; }
+  .icon { @include button-size(1px); }
+}
+
+@media (max-width: 600px) {
+  .checkout-button { display: none; }
+}
+
+@keyframes spin {
+  to { transform: rotate(360deg); }
+}
+
+:root { --brand: blue; }
+"#;
+
+        let symbols = stylesheet_scanner::scan(scss_code, true);
+
+        let found: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.start_line, s.end_line))
+            .collect();
+        assert_eq!(found, vec![
+            ("$primary", SymbolKind::Variable, 1, 1),
+            ("button-size", SymbolKind::Macro, 5, 7),
+            ("double", SymbolKind::Function, 9, 11),
+            (".checkout-button, #buy", SymbolKind::Selector, 13, 18),
+            ("&:hover", SymbolKind::Selector, 16, 16),
+            (".icon", SymbolKind::Selector, 17, 17),
+            ("@media (max-width: 600px)", SymbolKind::Module, 20, 22),
+            (".checkout-button", SymbolKind::Selector, 21, 21),
+            ("spin", SymbolKind::Class, 24, 26),
+            (":root", SymbolKind::Selector, 28, 28),
+            ("--brand", SymbolKind::Variable, 28, 28),
+        ]);
+
+        // Nested selectors are qualified with their parents resolved
+        assert_eq!(symbols[4].qualified_name.as_deref(), Some(".checkout-button:hover, #buy:hover"));
+        assert_eq!(symbols[5].container.as_deref(), Some(".checkout-button, #buy"));
+        assert_eq!(symbols[7].container.as_deref(), Some("@media (max-width: 600px)"));
+        assert_eq!(&scss_code[symbols[1].name_range.clone().unwrap()], "button-size");
+    }
+
+    #[test]
+    fn test_less_and_css_extraction() {
+        let less_code = r#"@primary: #333; // brand
+.bordered(@width: 2px) {
+  border: @width solid black;
+}
+#header {
+  &-title { color: @primary; }
+}
+"#;
+        let symbols = stylesheet_scanner::scan(less_code, true);
+        let found: Vec<_> = symbols.iter()
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.kind))
+            .collect();
+        assert_eq!(found, vec![
+            ("@primary", SymbolKind::Variable),
+            ("bordered", SymbolKind::Macro),
+            ("#header", SymbolKind::Selector),
+            ("#header-title", SymbolKind::Selector),
+        ]);
+
+        // `//` is not a comment in CSS, and never inside `url()`
+        let css_code = "a[href^=\"http://\"] { background: url(http://example.com/x.png); }\n.b { color: red }\n";
+        let symbols = stylesheet_scanner::scan(css_code, false);
+        let names: Vec<_> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["a[href^=\"http://\"]", ".b"]);
+    }
+
+    #[test]
+    fn test_stylesheet_non_ascii() {
+        let mut parser = CodeParser::new(get_language("app.html").unwrap()).unwrap();
+
+        let css_code = "/* thème */\n.café { content: \"déjà\"; }\n.naïve:hover { color: red }\n";
+        let symbols = parser.extract_symbols(css_code, "menu.css", None).unwrap();
+        let names: Vec<_> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec![".café", ".naïve:hover"]);
+        assert_eq!(symbols[1].start_line, 3);
+
+        let scss_code = "$thé: #c0ffee; // thé vert\n.café {\n  /* crème */\n  &-crème { color: $thé; }\n}\n";
+        let symbols = parser.extract_symbols(scss_code, "menu.scss", None).unwrap();
+        let names: Vec<_> = symbols.iter().map(|s| s.qualified_name.as_deref().unwrap()).collect();
+        assert_eq!(names, vec!["$thé", ".café", ".café-crème"]);
+        assert_eq!(&scss_code[symbols[2].name_range.clone().unwrap()], "&-crème");
+
+        let less_code = "@thé: vert; // thé\n.bordé(@épaisseur) { border: @épaisseur solid; }\n#entête { color: @thé; }\n";
+        let symbols = parser.extract_symbols(less_code, "menu.less", None).unwrap();
+        let names: Vec<_> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["@thé", "bordé", "#entête"]);
+    }
+
+    #[test]
+    fn test_stylesheet_filtering() {
+        let css_code = ":root { --gap: 4px; }\n.checkout-button { margin: var(--gap); }\n";
+
+        let mut parser = CodeParser::new(get_language("app.html").unwrap()).unwrap();
+
+        let mut selector_filter = HashSet::new();
+        selector_filter.insert(SymbolKind::Selector);
+
+        let symbols = parser.extract_symbols(css_code, "styles/app.css", Some(selector_filter)).unwrap();
+
+        let names: Vec<_> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec![":root", ".checkout-button"]);
+    }
+}
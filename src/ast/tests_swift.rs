@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::swift_visitor::SwiftVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_swift_type_extraction() {
+        let swift_code = r#"
+import Foundation
+
+class Order: Codable {
+    let id: String
+    var total: Int = 0
+
+    init(id: String) {
+        self.id = id
+    }
+
+    func pay() {
+        let receipt = "local"
+    }
+}
+
+struct Point {
+    var x: Int, y: Int
+}
+
+enum Color {
+    case red, green
+}
+
+typealias Handler = (String) -> Void
+"#;
+
+        let visitor = SwiftVisitor::new();
+        let language = get_language("Order.swift").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(swift_code, visitor).unwrap();
+
+        let order = symbols.iter().find(|s| s.name == "Order").unwrap();
+        assert_eq!(order.kind, SymbolKind::Class);
+        assert_eq!(order.start_line, 4);
+        assert_eq!(order.end_line, 15);
+
+        let properties: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Property && s.container.as_deref() == Some("Order"))
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(properties, vec!["id", "total"]);
+
+        let init = symbols.iter().find(|s| s.kind == SymbolKind::Constructor).unwrap();
+        assert_eq!(init.start_line, 8);
+        assert_eq!(init.container.as_deref(), Some("Order"));
+
+        let pay = symbols.iter().find(|s| s.name == "pay").unwrap();
+        assert_eq!(pay.kind, SymbolKind::Method);
+        assert_eq!(pay.qualified_name.as_deref(), Some("Order.pay"));
+        assert!(!symbols.iter().any(|s| s.name == "receipt"));
+
+        let point = symbols.iter().find(|s| s.name == "Point").unwrap();
+        assert_eq!(point.kind, SymbolKind::Struct);
+        assert!(symbols.iter().any(|s| s.name == "y" && s.container.as_deref() == Some("Point")));
+
+        let color = symbols.iter().find(|s| s.name == "Color").unwrap();
+        assert_eq!(color.kind, SymbolKind::Enum);
+
+        let handler = symbols.iter().find(|s| s.name == "Handler").unwrap();
+        assert_eq!(handler.kind, SymbolKind::Type);
+    }
+
+    #[test]
+    fn test_swift_protocols_and_extensions() {
+        let swift_code = r#"
+protocol Shape {
+    func area() -> Double
+    var name: String { get }
+}
+
+extension Order: Shape {
+    func area() -> Double { 0 }
+}
+
+extension Array where Element == Int {
+    func sum() -> Int { 0 }
+}
+
+func topLevel() {}
+"#;
+
+        let visitor = SwiftVisitor::new();
+        let language = get_language("Shape.swift").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(swift_code, visitor).unwrap();
+
+        let shape = symbols.iter().find(|s| s.name == "Shape").unwrap();
+        assert_eq!(shape.kind, SymbolKind::Interface);
+        assert_eq!(shape.start_line, 2);
+
+        let requirement = symbols.iter().find(|s| s.name == "name").unwrap();
+        assert_eq!(requirement.kind, SymbolKind::Property);
+        assert_eq!(requirement.container.as_deref(), Some("Shape"));
+
+        let area = symbols.iter().find(|s| s.name == "area" && s.start_line == 8).unwrap();
+        assert_eq!(area.kind, SymbolKind::Method);
+        assert_eq!(area.container.as_deref(), Some("Order"));
+
+        let sum = symbols.iter().find(|s| s.name == "sum").unwrap();
+        assert_eq!(sum.qualified_name.as_deref(), Some("Array.sum"));
+
+        let top_level = symbols.iter().find(|s| s.name == "topLevel").unwrap();
+        assert_eq!(top_level.kind, SymbolKind::Function);
+        assert!(top_level.container.is_none());
+    }
+
+    #[test]
+    fn test_swift_filtering() {
+        let swift_code = r#"
+struct Config {
+    var port = 8080
+    func load() {}
+}
+"#;
+
+        let language = get_language("Config.swift").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut struct_filter = HashSet::new();
+        struct_filter.insert(SymbolKind::Struct);
+
+        let symbols = parser.extract_symbols(swift_code, "Config.swift", Some(struct_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "Config");
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser};
+    use std::path::Path;
+
+    #[test]
+    fn test_thrift_definition_extraction() {
+        let thrift_code = r#"include "shared.thrift"
+namespace java com.example.orders
+
+typedef map<string, list<i32>> Index
+
+const list<string> REGIONS = [
+  "eu",
+  "us",
+]
+
+/* An order */
+struct Order {
+  1: required string id,
+  2: optional list<Item> items = [],
+}
+
+exception NotFound { 1: string message }
+
+union Payment { 1: string card; 2: string iban }
+
+enum Status {
+  OPEN = 1,
+  PAID = 2
+}
+"#;
+
+        let mut parser = CodeParser::for_file(Path::new("orders.thrift")).unwrap();
+        let symbols = parser.extract_symbols(thrift_code, "orders.thrift", None).unwrap();
+
+        let kinds: Vec<_> = symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.start_line, s.end_line))
+            .collect();
+        assert_eq!(kinds, vec![
+            ("shared.thrift", SymbolKind::Import, 1, 1),
+            ("com.example.orders", SymbolKind::Namespace, 2, 2),
+            ("Index", SymbolKind::Type, 4, 4),
+            ("REGIONS", SymbolKind::Constant, 6, 9),
+            ("Order", SymbolKind::Struct, 12, 15),
+            ("NotFound", SymbolKind::Struct, 17, 17),
+            ("Payment", SymbolKind::Union, 19, 19),
+            ("Status", SymbolKind::Enum, 21, 24),
+        ]);
+
+        let order = symbols.iter().find(|s| s.name == "Order").unwrap();
+        assert_eq!(&thrift_code[order.name_range.clone().unwrap()], "Order");
+    }
+
+    #[test]
+    fn test_thrift_service_extraction() {
+        let thrift_code = r#"service OrderService extends shared.BaseService {
+  // Creates an order
+  Order CreateOrder(1: Order order) throws (1: NotFound nf),
+  oneway void Ping();
+  map<string, Order> ListOrders(
+    1: i32 limit
+  ) (deprecated = "true")
+}
+"#;
+
+        let mut parser = CodeParser::for_file(Path::new("orders.thrift")).unwrap();
+        let symbols = parser.extract_symbols(thrift_code, "orders.thrift", None).unwrap();
+
+        let service = &symbols[0];
+        assert_eq!(service.kind, SymbolKind::Interface);
+        assert_eq!(service.end_line, 8);
+
+        let methods: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Method)
+            .map(|s| (s.qualified_name.as_deref().unwrap(), s.start_line, s.end_line))
+            .collect();
+        assert_eq!(methods, vec![
+            ("OrderService.CreateOrder", 3, 3),
+            ("OrderService.Ping", 4, 4),
+            ("OrderService.ListOrders", 5, 7),
+        ]);
+
+        // Files that are neither parsed nor scanned are not supported
+        assert!(CodeParser::for_file(Path::new("notes.txt")).is_none());
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{CodeParser, get_language};
+    use super::super::toml_visitor::TomlVisitor;
+
+    #[test]
+    fn test_toml_key_extraction() {
+        let toml_code = r#"title = "x"
+server.port = 80
+
+[database]
+url = "postgres://"
+limits = { max = 5 }
+
+[tool."poetry.dev"]
+strict = true
+
+[[bin]]
+name = "a"
+
+[[bin]]
+name = "b"
+paths = [{ src = "b.rs" }]
+"#;
+
+        let visitor = TomlVisitor::new();
+        let language = get_language("Config.toml").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(toml_code, visitor).unwrap();
+
+        let paths: Vec<_> = symbols.iter()
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(paths, vec![
+            "title",
+            "server.port",
+            "database",
+            "database.url",
+            "database.limits",
+            "database.limits.max",
+            "tool.poetry.dev",
+            "tool.poetry.dev.strict",
+            "bin[0]",
+            "bin[0].name",
+            "bin[1]",
+            "bin[1].name",
+            "bin[1].paths",
+            "bin[1].paths[0].src",
+        ]);
+
+        // A table spans its keys up to the next header
+        let database = symbols.iter().find(|s| s.name == "database").unwrap();
+        assert_eq!(database.start_line, 4);
+        assert_eq!(database.end_line, 6);
+
+        let port = symbols.iter().find(|s| s.name == "port").unwrap();
+        assert_eq!(port.container.as_deref(), Some("server"));
+
+        let dev = symbols.iter().find(|s| s.name == "poetry.dev").unwrap();
+        assert_eq!(dev.container.as_deref(), Some("tool"));
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::yaml_visitor::YamlVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_yaml_key_extraction() {
+        let yaml_code = r#"version: "3"
+services:
+  api:
+    image: app
+    env:
+      - KEY=1
+  "db": { image: postgres, ports: [5432] }
+"#;
+
+        let visitor = YamlVisitor::new();
+        let language = get_language("docker-compose.yml").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(yaml_code, visitor).unwrap();
+
+        let paths: Vec<_> = symbols.iter()
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(paths, vec![
+            "version",
+            "services",
+            "services.api",
+            "services.api.image",
+            "services.api.env",
+            "services.db",
+            "services.db.image",
+            "services.db.ports",
+        ]);
+
+        let api = symbols.iter().find(|s| s.name == "api").unwrap();
+        assert_eq!(api.kind, SymbolKind::Property);
+        assert_eq!(api.start_line, 3);
+        assert_eq!(api.end_line, 6);
+
+        let env = symbols.iter().find(|s| s.name == "env").unwrap();
+        assert_eq!(env.container.as_deref(), Some("services.api"));
+        assert_eq!(env.end_line, 6);
+
+        let services = symbols.iter().find(|s| s.name == "services").unwrap();
+        assert_eq!(services.end_line, 7);
+    }
+
+    #[test]
+    fn test_yaml_sequences_and_documents() {
+        let yaml_code = r#"defaults: &defaults
+  retries: 3
+steps:
+  - name: build
+    run: make
+  - name: test
+    <<: *defaults
+---
+kind: Service
+"#;
+
+        let visitor = YamlVisitor::new();
+        let language = get_language("ci.yaml").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(yaml_code, visitor).unwrap();
+
+        let steps: Vec<_> = symbols.iter()
+            .filter(|s| s.container.as_deref().is_some_and(|c| c.starts_with("steps")))
+            .map(|s| s.qualified_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(steps, vec!["steps[0].name", "steps[0].run", "steps[1].name"]);
+
+        let kind = symbols.iter().find(|s| s.name == "kind").unwrap();
+        assert_eq!(kind.start_line, 9);
+        assert_eq!(kind.container, None);
+    }
+
+    #[test]
+    fn test_yaml_filtering() {
+        let yaml_code = "name: app\n";
+
+        let language = get_language("app.yml").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut function_filter = HashSet::new();
+        function_filter.insert(SymbolKind::Function);
+
+        let symbols = parser.extract_symbols(yaml_code, "app.yml", Some(function_filter)).unwrap();
+        assert!(symbols.is_empty());
+
+        let symbols = parser.extract_symbols(yaml_code, "app.yml", None).unwrap();
+        assert_eq!(symbols.len(), 1);
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{SymbolKind, CodeParser, get_language};
+    use super::super::zig_visitor::ZigVisitor;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_zig_container_extraction() {
+        let zig_code = r#"const std = @import("std");
+
+pub const Point = struct {
+    x: i32,
+    y: i32,
+
+    pub fn init(x: i32, y: i32) Point {
+        const p = Point{ .x = x, .y = y };
+        return p;
+    }
+};
+
+const Color = enum { red, green };
+const Value = union(enum) { int: i32, float: f64 };
+"#;
+
+        let visitor = ZigVisitor::new();
+        let language = get_language("point.zig").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(zig_code, visitor).unwrap();
+
+        let point = symbols.iter().find(|s| s.name == "Point").unwrap();
+        assert_eq!(point.kind, SymbolKind::Struct);
+        assert_eq!(point.start_line, 3);
+        assert_eq!(point.end_line, 11);
+
+        let init = symbols.iter().find(|s| s.name == "init").unwrap();
+        assert_eq!(init.kind, SymbolKind::Method);
+        assert_eq!(init.container.as_deref(), Some("Point"));
+        assert_eq!(init.qualified_name.as_deref(), Some("Point.init"));
+
+        assert_eq!(symbols.iter().find(|s| s.name == "Color").unwrap().kind, SymbolKind::Enum);
+        assert_eq!(symbols.iter().find(|s| s.name == "Value").unwrap().kind, SymbolKind::Union);
+
+        let import = symbols.iter().find(|s| s.kind == SymbolKind::Import).unwrap();
+        assert_eq!(import.name, "std");
+
+        // Function locals are not symbols
+        assert!(!symbols.iter().any(|s| s.name == "p"));
+    }
+
+    #[test]
+    fn test_zig_functions_and_tests() {
+        let zig_code = r#"
+const max_len = 64;
+var counter: u32 = 0;
+
+fn helper() void {}
+
+pub fn main() !void {}
+
+test "basic add" {
+    try std.testing.expect(1 + 1 == 2);
+}
+
+test helper {}
+"#;
+
+        let visitor = ZigVisitor::new();
+        let language = get_language("main.zig").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let symbols = parser.parse_with_visitor(zig_code, visitor).unwrap();
+
+        let functions: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Function)
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(functions, vec!["helper", "main"]);
+
+        assert_eq!(symbols.iter().find(|s| s.name == "max_len").unwrap().kind, SymbolKind::Constant);
+        assert_eq!(symbols.iter().find(|s| s.name == "counter").unwrap().kind, SymbolKind::Variable);
+
+        let tests: Vec<_> = symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Test)
+            .collect();
+        assert_eq!(tests.len(), 2);
+        assert_eq!(tests[0].name, "basic add");
+        assert_eq!(tests[0].start_line, 9);
+        assert_eq!(tests[0].end_line, 11);
+        assert_eq!(tests[1].name, "helper");
+    }
+
+    #[test]
+    fn test_zig_filtering() {
+        let zig_code = r#"
+const List = struct {
+    pub fn append(self: *List) void {}
+};
+fn run() void {}
+"#;
+
+        let language = get_language("list.zig").unwrap();
+        let mut parser = CodeParser::new(language).unwrap();
+
+        let mut struct_filter = HashSet::new();
+        struct_filter.insert(SymbolKind::Struct);
+
+        let symbols = parser.extract_symbols(zig_code, "list.zig", Some(struct_filter)).unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "List");
+    }
+}
@@ -0,0 +1,215 @@
+//! Thrift IDL has no tree-sitter grammar for our tree-sitter version, so its
+//! definitions are found with a small tokenizer instead. Thrift's top level
+//! is regular enough that keywords and brace matching are all we need.
+
+use super::symbol::{Symbol, SymbolKind};
+use std::ops::Range;
+
+struct Token<'a> {
+    text: &'a str,
+    range: Range<usize>,
+}
+
+/// Split the source into identifiers, strings and single punctuation
+/// characters, skipping whitespace and `//`, `#` and `/* */` comments.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        let start = i;
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        } else if c == b'#' || source[i..].starts_with("//") {
+            i = source[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            continue;
+        } else if source[i..].starts_with("/*") {
+            i = source[i + 2..].find("*/").map_or(bytes.len(), |end| i + 2 + end + 2);
+            continue;
+        } else if c == b'"' || c == b'\'' {
+            i += 1;
+            while i < bytes.len() && bytes[i] != c {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(bytes.len());
+        } else if c.is_ascii_alphanumeric() || c == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] == b'.') {
+                i += 1;
+            }
+        } else {
+            i += source[i..].chars().next().map_or(1, char::len_utf8);
+        }
+        tokens.push(Token { text: &source[start..i], range: start..i });
+    }
+    tokens
+}
+
+fn is_identifier(token: &Token) -> bool {
+    token.text.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+}
+
+/// Index of the token closing the bracket opened at `open`.
+fn matching(tokens: &[Token], open: usize) -> Option<usize> {
+    let (left, right) = match tokens[open].text {
+        "{" => ("{", "}"),
+        "(" => ("(", ")"),
+        "[" => ("[", "]"),
+        "<" => ("<", ">"),
+        _ => return None,
+    };
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        if token.text == left {
+            depth += 1;
+        } else if token.text == right {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Skip a field type such as `i32` or `map<string, list<Item>>`, returning
+/// the index of the token after it.
+fn skip_type(tokens: &[Token], at: usize) -> usize {
+    match tokens.get(at + 1) {
+        Some(next) if next.text == "<" => matching(tokens, at + 1).map_or(tokens.len(), |close| close + 1),
+        _ => at + 1,
+    }
+}
+
+fn named(kind: SymbolKind, name: &Token, container: Option<&str>, source: &str, range: Range<usize>) -> Symbol {
+    let qualified_name = match container {
+        Some(container) => format!("{}.{}", container, name.text),
+        None => name.text.to_string(),
+    };
+
+    Symbol {
+        name_range: Some(name.range.clone()),
+        container: container.map(|c| c.to_string()),
+        qualified_name: Some(qualified_name),
+        ..Symbol::from_span(kind, name.text.to_string(), source, range)
+    }
+}
+
+/// Functions of a service body: `[oneway] ReturnType name(fields) [throws (fields)]`,
+/// optionally followed by annotations and a `,` or `;` separator.
+fn scan_service_functions(tokens: &[Token], body: Range<usize>, service: &str, source: &str) -> Vec<Symbol> {
+    let mut symbols = Vec::new();
+    let mut start = body.start;
+
+    let mut i = body.start;
+    while i + 1 < body.end {
+        if !(is_identifier(&tokens[i]) && tokens[i + 1].text == "(") {
+            i += 1;
+            continue;
+        }
+        let Some(mut end) = matching(tokens, i + 1) else {
+            break;
+        };
+        if tokens.get(end + 1).is_some_and(|t| t.text == "throws")
+            && tokens.get(end + 2).is_some_and(|t| t.text == "(")
+            && let Some(close) = matching(tokens, end + 2)
+        {
+            end = close;
+        }
+
+        let range = tokens[start].range.start..tokens[end].range.end;
+        symbols.push(named(SymbolKind::Method, &tokens[i], Some(service), source, range));
+
+        // Annotations and the list separator belong to the previous function
+        i = end + 1;
+        if tokens.get(i).is_some_and(|t| t.text == "(") {
+            i = matching(tokens, i).map_or(body.end, |close| close + 1);
+        }
+        if tokens.get(i).is_some_and(|t| t.text == "," || t.text == ";") {
+            i += 1;
+        }
+        start = i;
+    }
+    symbols
+}
+
+pub fn scan(source: &str) -> Vec<Symbol> {
+    let tokens = tokenize(source);
+    let mut symbols = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let keyword = tokens[i].text;
+        let start = tokens[i].range.start;
+        match keyword {
+            "include" | "cpp_include" => {
+                if let Some(path) = tokens.get(i + 1) {
+                    let name = path.text.trim_matches(|c| c == '"' || c == '\'').to_string();
+                    symbols.push(Symbol {
+                        name_range: Some(path.range.clone()),
+                        ..Symbol::from_span(SymbolKind::Import, name, source, start..path.range.end)
+                    });
+                }
+                i += 2;
+            }
+            // `namespace <language> <name>`
+            "namespace" => {
+                if let Some(name) = tokens.get(i + 2) {
+                    symbols.push(named(SymbolKind::Namespace, name, None, source, start..name.range.end));
+                }
+                i += 3;
+            }
+            "typedef" => {
+                let name_at = skip_type(&tokens, i + 1);
+                if let Some(name) = tokens.get(name_at) {
+                    symbols.push(named(SymbolKind::Type, name, None, source, start..name.range.end));
+                }
+                i = name_at + 1;
+            }
+            // `const <type> <name> = <value>`, where the value may be a
+            // multi-line list or map
+            "const" => {
+                let name_at = skip_type(&tokens, i + 1);
+                let Some(name) = tokens.get(name_at) else {
+                    break;
+                };
+                let mut end = name_at;
+                if tokens.get(end + 1).is_some_and(|t| t.text == "=") && end + 2 < tokens.len() {
+                    end = matching(&tokens, end + 2).unwrap_or(end + 2);
+                }
+                symbols.push(named(SymbolKind::Constant, name, None, source, start..tokens[end].range.end));
+                i = end + 1;
+            }
+            "struct" | "union" | "exception" | "enum" | "senum" | "service" => {
+                let kind = match keyword {
+                    "union" => SymbolKind::Union,
+                    "enum" | "senum" => SymbolKind::Enum,
+                    "service" => SymbolKind::Interface,
+                    _ => SymbolKind::Struct,
+                };
+                let Some(name) = tokens.get(i + 1).filter(|t| is_identifier(t)) else {
+                    i += 1;
+                    continue;
+                };
+                let Some(open) = tokens.iter().skip(i + 2).position(|t| t.text == "{").map(|p| p + i + 2) else {
+                    break;
+                };
+                let Some(close) = matching(&tokens, open) else {
+                    break;
+                };
+
+                let mut symbol = named(kind, name, None, source, start..tokens[close].range.end);
+                symbol.body_range = Some(tokens[open].range.start..tokens[close].range.end);
+                symbols.push(symbol);
+                if kind == SymbolKind::Interface {
+                    symbols.extend(scan_service_functions(&tokens, open + 1..close, name.text, source));
+                }
+                i = close + 1;
+            }
+            _ => i += 1,
+        }
+    }
+    symbols
+}
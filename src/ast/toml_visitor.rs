@@ -0,0 +1,146 @@
+use super::key_path;
+use super::symbol::Symbol;
+use super::visitor::LanguageVisitor;
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+pub struct TomlVisitor {
+    symbols: Vec<Symbol>,
+    /// Keys (and array indices) leading to the current value, starting with
+    /// the enclosing `[table]` header.
+    path: Vec<String>,
+    /// Number of `[[array]]` tables seen so far for each header path.
+    array_tables: HashMap<String, usize>,
+}
+
+impl TomlVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            path: Vec::new(),
+            array_tables: HashMap::new(),
+        }
+    }
+
+    /// Segments of a bare, quoted or dotted key, e.g. `tool."poetry.dev"`.
+    fn key_segments<'tree>(key: &Node<'tree>, source: &str, segments: &mut Vec<(String, Node<'tree>)>) {
+        match key.kind() {
+            "dotted_key" => {
+                let mut cursor = key.walk();
+                for child in key.named_children(&mut cursor) {
+                    Self::key_segments(&child, source, segments);
+                }
+            }
+            "bare_key" | "quoted_key" => {
+                if let Ok(text) = key.utf8_text(source.as_bytes()) {
+                    let name = text.trim_matches(|c| c == '"' || c == '\'');
+                    segments.push((name.to_string(), *key));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Header of a `[table]` or `[[array]]`, returned as the name and
+    /// parent path of the table along with its key node.
+    fn header<'tree>(node: &Node<'tree>, source: &str) -> Option<(Vec<String>, String, Node<'tree>)> {
+        let mut segments = Vec::new();
+        Self::key_segments(&node.named_child(0)?, source, &mut segments);
+        let (name, name_node) = segments.pop()?;
+        let parent = segments.into_iter().map(|(segment, _)| segment).collect();
+        Some((parent, name, name_node))
+    }
+
+    /// Table nodes run up to the next header, blank lines included; end the
+    /// symbol on the table's last entry instead.
+    fn table_symbol(parent: &[String], name: String, name_node: &Node, node: &Node) -> Symbol {
+        let mut symbol = key_path::key_symbol(parent, name, name_node, node, None);
+        if let Some(last) = node.named_child(node.named_child_count().saturating_sub(1)) {
+            symbol.end_line = last.end_position().row + 1;
+        }
+        symbol
+    }
+
+    fn visit_pair(&mut self, node: &Node, source_code: &str) {
+        let Some(key) = node.named_child(0) else {
+            return;
+        };
+        let mut segments = Vec::new();
+        Self::key_segments(&key, source_code, &mut segments);
+        let Some((name, name_node)) = segments.pop() else {
+            return;
+        };
+        let value = node.named_child(node.named_child_count().saturating_sub(1)).filter(|v| v.id() != key.id());
+
+        // `server.port = 80` is the key `port` inside `server`
+        let depth = self.path.len();
+        self.path.extend(segments.into_iter().map(|(segment, _)| segment));
+        self.symbols.push(key_path::key_symbol(&self.path, name.clone(), &name_node, node, value.as_ref()));
+        if let Some(value) = value {
+            self.path.push(name);
+            self.visit(&value, source_code);
+        }
+        self.path.truncate(depth);
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for TomlVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "pair" => self.visit_pair(node, source_code),
+            "table" => {
+                let Some((parent, name, name_node)) = Self::header(node, source_code) else {
+                    return;
+                };
+                self.symbols.push(Self::table_symbol(&parent, name.clone(), &name_node, node));
+                self.path = parent;
+                self.path.push(name);
+                self.visit_children(node, source_code);
+                self.path.clear();
+            }
+            "table_array_element" => {
+                let Some((parent, name, name_node)) = Self::header(node, source_code) else {
+                    return;
+                };
+                let mut symbol = Self::table_symbol(&parent, name.clone(), &name_node, node);
+                let array = symbol.qualified_name.clone().unwrap_or_default();
+                let index = self.array_tables.entry(array.clone()).or_insert(0);
+                let element = format!("[{}]", index);
+                *index += 1;
+
+                // Each `[[bin]]` is an element of the `bin` array: `bin[0]`, `bin[1]`
+                symbol.qualified_name = Some(format!("{}{}", array, element));
+                self.symbols.push(symbol);
+                self.path = parent;
+                self.path.push(name);
+                self.path.push(element);
+                self.visit_children(node, source_code);
+                self.path.clear();
+            }
+            "array" => {
+                let mut cursor = node.walk();
+                let items: Vec<Node> = node.named_children(&mut cursor)
+                    .filter(|c| c.kind() != "comment")
+                    .collect();
+                for (index, item) in items.iter().enumerate() {
+                    self.path.push(format!("[{}]", index));
+                    self.visit(item, source_code);
+                    self.path.pop();
+                }
+            }
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -1,7 +1,28 @@
-use super::symbol::Symbol;
+use super::symbol::{Symbol, SymbolKind};
 use tree_sitter::Node;
 
 pub trait LanguageVisitor {
     fn visit(&mut self, node: &Node, source_code: &str);
     fn get_symbols(self) -> Vec<Symbol>;
 }
+
+/// Container named by the stack of enclosing scopes, `com.shop.Order` or
+/// `orders::Order`; `None` at the top level.
+pub fn scope_container(scope: &[String], separator: &str) -> Option<String> {
+    (!scope.is_empty()).then(|| scope.join(separator))
+}
+
+/// A symbol spanning `node` inside `container`, qualified with the
+/// language's `separator`: `Order.pay` or `Order::pay`.
+pub fn scoped_symbol(kind: SymbolKind, name: String, container: Option<String>, separator: &str, node: &Node) -> Symbol {
+    let qualified_name = match &container {
+        Some(container) => format!("{}{}{}", container, separator, name),
+        None => name.clone(),
+    };
+
+    Symbol {
+        container,
+        qualified_name: Some(qualified_name),
+        ..Symbol::new(kind, name, node)
+    }
+}
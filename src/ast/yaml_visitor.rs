@@ -0,0 +1,77 @@
+use super::key_path;
+use super::symbol::Symbol;
+use super::visitor::LanguageVisitor;
+use tree_sitter::Node;
+
+pub struct YamlVisitor {
+    symbols: Vec<Symbol>,
+    /// Keys (and sequence indices) leading to the current value.
+    path: Vec<String>,
+}
+
+impl YamlVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            path: Vec::new(),
+        }
+    }
+
+    /// Scalar key text without quotes. Merge keys (`<<: *defaults`) are not
+    /// symbols of their own.
+    fn key_name(key: &Node, source: &str) -> Option<String> {
+        let text = key.utf8_text(source.as_bytes()).ok()?.trim();
+        let name = text.trim_matches(|c| c == '"' || c == '\'');
+        if name == "<<" {
+            return None;
+        }
+        Some(name.to_string())
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for YamlVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "block_mapping_pair" | "flow_pair" => {
+                let Some(key) = node.child_by_field_name("key") else {
+                    return;
+                };
+                let Some(name) = Self::key_name(&key, source_code) else {
+                    return;
+                };
+                let value = node.child_by_field_name("value");
+
+                self.symbols.push(key_path::key_symbol(&self.path, name.clone(), &key, node, value.as_ref()));
+                if let Some(value) = value {
+                    self.path.push(name);
+                    self.visit(&value, source_code);
+                    self.path.pop();
+                }
+            }
+            "block_sequence" | "flow_sequence" => {
+                let mut cursor = node.walk();
+                let items: Vec<Node> = node.named_children(&mut cursor)
+                    .filter(|c| matches!(c.kind(), "block_sequence_item" | "flow_node" | "flow_pair"))
+                    .collect();
+                for (index, item) in items.iter().enumerate() {
+                    self.path.push(format!("[{}]", index));
+                    self.visit(item, source_code);
+                    self.path.pop();
+                }
+            }
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
@@ -0,0 +1,131 @@
+use super::symbol::{Symbol, SymbolKind};
+use super::visitor::{self, LanguageVisitor};
+use tree_sitter::Node;
+
+pub struct ZigVisitor {
+    symbols: Vec<Symbol>,
+    /// Names of the enclosing container declarations (`const X = struct {}`).
+    scope: Vec<String>,
+}
+
+impl ZigVisitor {
+    pub fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            scope: Vec::new(),
+        }
+    }
+
+    fn scoped_symbol(&self, kind: SymbolKind, name_node: &Node, node: &Node, source: &str) -> Option<Symbol> {
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.to_string();
+        let container = visitor::scope_container(&self.scope, ".");
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            ..visitor::scoped_symbol(kind, name, container, ".", node)
+        })
+    }
+
+    /// Kind of a `const`/`var` declaration follows from its value: containers
+    /// become types (and scope their members), `@import(...)` an import, and
+    /// anything else a constant or variable.
+    fn visit_declaration(&mut self, node: &Node, source_code: &str) {
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.children(&mut cursor).collect();
+        let Some(name_node) = children.iter().find(|c| c.kind() == "identifier") else {
+            return;
+        };
+        let is_const = children.iter().any(|c| c.kind() == "const");
+        let value = children.iter().rev().find(|c| c.is_named() && c.id() != name_node.id());
+
+        let container_kind = value.and_then(|v| match v.kind() {
+            "struct_declaration" | "opaque_declaration" => Some(SymbolKind::Struct),
+            "enum_declaration" => Some(SymbolKind::Enum),
+            "union_declaration" => Some(SymbolKind::Union),
+            _ => None,
+        });
+
+        if let Some(kind) = container_kind {
+            if let Some(mut symbol) = self.scoped_symbol(kind, name_node, node, source_code) {
+                symbol.body_range = value.map(|v| v.byte_range());
+                self.scope.push(symbol.name.clone());
+                self.symbols.push(symbol);
+                self.visit_children(node, source_code);
+                self.scope.pop();
+            }
+            return;
+        }
+
+        let import = value
+            .filter(|v| v.kind() == "builtin_function")
+            .filter(|v| v.child(0).and_then(|b| b.utf8_text(source_code.as_bytes()).ok()) == Some("@import"));
+        let symbol = if let Some(import) = import {
+            // `const std = @import("std");` is named after the imported module
+            let path = import.utf8_text(source_code.as_bytes()).ok()
+                .and_then(|text| text.split('"').nth(1))
+                .map(|path| path.to_string());
+            path.map(|path| Symbol {
+                name_range: Some(name_node.byte_range()),
+                ..Symbol::new(SymbolKind::Import, path, node)
+            })
+        } else {
+            let kind = if is_const { SymbolKind::Constant } else { SymbolKind::Variable };
+            self.scoped_symbol(kind, name_node, node, source_code)
+        };
+
+        if let Some(symbol) = symbol {
+            self.symbols.push(symbol);
+        }
+    }
+
+    fn extract_test(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let mut cursor = node.walk();
+        let name_node = node.children(&mut cursor).find(|c| matches!(c.kind(), "string" | "identifier"))?;
+        let name = name_node.utf8_text(source.as_bytes()).ok()?.trim_matches('"').to_string();
+
+        let mut cursor = node.walk();
+        let body = node.children(&mut cursor).find(|c| c.kind() == "block");
+
+        Some(Symbol {
+            name_range: Some(name_node.byte_range()),
+            body_range: body.map(|n| n.byte_range()),
+            ..Symbol::new(SymbolKind::Test, name, node)
+        })
+    }
+
+    fn visit_children(&mut self, node: &Node, source_code: &str) {
+        for i in 0..node.child_count() {
+            if let Some(child) = node.child(i) {
+                self.visit(&child, source_code);
+            }
+        }
+    }
+}
+
+impl LanguageVisitor for ZigVisitor {
+    fn visit(&mut self, node: &Node, source_code: &str) {
+        match node.kind() {
+            "function_declaration" => {
+                let kind = if self.scope.is_empty() { SymbolKind::Function } else { SymbolKind::Method };
+                if let Some(name_node) = node.child_by_field_name("name")
+                    && let Some(mut symbol) = self.scoped_symbol(kind, &name_node, node, source_code)
+                {
+                    symbol.body_range = node.child_by_field_name("body").map(|n| n.byte_range());
+                    self.symbols.push(symbol);
+                }
+            }
+            "variable_declaration" => self.visit_declaration(node, source_code),
+            "test_declaration" => {
+                if let Some(symbol) = self.extract_test(node, source_code) {
+                    self.symbols.push(symbol);
+                }
+            }
+            // Locals inside function bodies are not symbols
+            _ => self.visit_children(node, source_code),
+        }
+    }
+
+    fn get_symbols(self) -> Vec<Symbol> {
+        self.symbols
+    }
+}
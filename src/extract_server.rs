@@ -38,4 +38,14 @@ impl ExtractSymbolsServer {
     pub async fn extract_symbols(&self, params: Parameters<ExtractSymbolsRequest>) -> Result<Json<Vec<FileSymbols>>, String> {
         extract_symbols(params.0).await.map(Json)
     }
+
+    #[tool(name = "api_report", description = "Report the public API of a package: public and exported symbols with their signatures and doc comments, grouped by module (file). Set markdown=true for a Markdown document instead of JSON.")]
+    pub async fn api_report(&self, params: Parameters<ApiReportRequest>) -> Result<String, String> {
+        api_report(params.0).await
+    }
+
+    #[tool(name = "api_diff", description = "Compare the public API of two versions of a package, e.g. two checkouts of different branches. Each side is a saved JSON report from api_report or a path pattern to report on. Lists breaking changes (removed symbols, changed signatures or kinds) and additions.")]
+    pub async fn api_diff(&self, params: Parameters<ApiDiffRequest>) -> Result<String, String> {
+        api_diff(params.0).await
+    }
 }
@@ -4,7 +4,6 @@ use rmcp::{
     ServiceExt,
     transport::stdio,
 };
-use tracing_subscriber;
 
 mod ast;
 mod server;
@@ -32,6 +31,45 @@ enum Commands {
         name_regex: Option<String>,
         #[arg(short, long)]
         pretty: bool,
+        /// Also extract symbols from Markdown code fences
+        #[arg(long)]
+        code_fences: bool,
+        /// Also report the headings of Markdown cells in Jupyter notebooks
+        #[arg(long)]
+        notebook_headings: bool,
+        /// Also report variables assigned inside Python functions
+        #[arg(long)]
+        python_locals: bool,
+        /// Only list symbols with this decorator or attribute, e.g. `pytest.fixture` or `test`
+        #[arg(long)]
+        decorator: Option<String>,
+        /// Only list the public API: public and exported symbols
+        #[arg(long)]
+        public_only: bool,
+        /// Define a C/C++ macro, `NAME` or `NAME=VALUE`, and skip `#if` branches left out (repeatable)
+        #[arg(short = 'D', long = "define")]
+        defines: Vec<String>,
+        /// Parse `.h` files as `c` or `cpp` instead of guessing from their content
+        #[arg(long, value_parser = ["c", "cpp"])]
+        header_language: Option<String>,
+    },
+    /// Report the public API of a package, grouped by module
+    ApiReport {
+        #[arg(short, long)]
+        file_path: String,
+        /// Print Markdown instead of JSON
+        #[arg(long)]
+        markdown: bool,
+    },
+    /// Compare two API reports, exiting with status 1 on breaking changes
+    ApiDiff {
+        /// Saved JSON report or path of the old version
+        old: String,
+        /// Saved JSON report or path of the new version
+        new: String,
+        /// Print Markdown instead of JSON
+        #[arg(long)]
+        markdown: bool,
     },
     Mcp,
 }
@@ -52,7 +90,7 @@ async fn main() -> Result<()> {
     let args = Args::parse();
 
     match args.command {
-        Some(Commands::ExtractSymbols { file_path, symbols, name_regex, pretty }) => {
+        Some(Commands::ExtractSymbols { file_path, symbols, name_regex, pretty, code_fences, notebook_headings, python_locals, decorator, public_only, defines, header_language }) => {
             let filter_kind = symbols.as_deref().and_then(|f| match f {
                 "function" => Some(ast::SymbolKind::Function),
                 "class" => Some(ast::SymbolKind::Class),
@@ -63,13 +101,47 @@ async fn main() -> Result<()> {
                 "trait" => Some(ast::SymbolKind::Trait),
                 "interface" => Some(ast::SymbolKind::Interface),
                 "type" => Some(ast::SymbolKind::Type),
+                "union" => Some(ast::SymbolKind::Union),
+                "record" => Some(ast::SymbolKind::Record),
+                "constructor" => Some(ast::SymbolKind::Constructor),
+                "destructor" => Some(ast::SymbolKind::Destructor),
+                "operator" => Some(ast::SymbolKind::Operator),
+                "field" => Some(ast::SymbolKind::Field),
+                "enum_member" => Some(ast::SymbolKind::EnumMember),
+                "namespace" => Some(ast::SymbolKind::Namespace),
+                "property" => Some(ast::SymbolKind::Property),
+                "event" => Some(ast::SymbolKind::Event),
+                "module" => Some(ast::SymbolKind::Module),
+                "constant" => Some(ast::SymbolKind::Constant),
+                "test" => Some(ast::SymbolKind::Test),
+                "import" => Some(ast::SymbolKind::Import),
+                "export" => Some(ast::SymbolKind::Export),
+                "impl" => Some(ast::SymbolKind::Impl),
+                "macro" => Some(ast::SymbolKind::Macro),
+                "section" => Some(ast::SymbolKind::Section),
+                "code_block" => Some(ast::SymbolKind::CodeBlock),
+                "table" => Some(ast::SymbolKind::Table),
+                "view" => Some(ast::SymbolKind::View),
+                "index" => Some(ast::SymbolKind::Index),
+                "selector" => Some(ast::SymbolKind::Selector),
+                "element" => Some(ast::SymbolKind::Element),
                 _ => None,
             });
             let req = ExtractSymbolsRequest { 
                 path_pattern: file_path, 
                 filter: filter_kind, 
                 start_line: None, 
-                end_line: None 
+                end_line: None,
+                code_fences: Some(code_fences),
+                notebook_headings: Some(notebook_headings),
+                python_locals: Some(python_locals),
+                decorator,
+                public_only: Some(public_only),
+                defines: (!defines.is_empty()).then_some(defines),
+                header_language: header_language.map(|language| match language.as_str() {
+                    "c" => ast::HeaderLanguage::C,
+                    _ => ast::HeaderLanguage::Cpp,
+                }),
             };
             match extract_symbols(req).await {
                 Ok(mut result) => {
@@ -81,9 +153,13 @@ async fn main() -> Result<()> {
                                 std::process::exit(1);
                             }
                         };
-                        // Filter symbols within each file
+                        // Filter symbols within each file, by bare or qualified
+                        // name (e.g. `^services\.api\.env$` in a compose file)
                         for file_symbols in &mut result {
-                            file_symbols.symbols.retain(|symbol| re.is_match(&symbol.name));
+                            file_symbols.symbols.retain(|symbol| {
+                                re.is_match(&symbol.name)
+                                    || symbol.qualified_name.as_deref().is_some_and(|q| re.is_match(q))
+                            });
                         }
                         // Remove files with no matching symbols
                         result.retain(|file_symbols| !file_symbols.symbols.is_empty());
@@ -102,8 +178,46 @@ async fn main() -> Result<()> {
                                     ast::SymbolKind::Trait => "[TRAIT]",
                                     ast::SymbolKind::Interface => "[INTERFACE]",
                                     ast::SymbolKind::Type => "[TYPE]",
+                                    ast::SymbolKind::Union => "[UNION]",
+                                    ast::SymbolKind::Record => "[RECORD]",
+                                    ast::SymbolKind::Constructor => "[CONSTRUCTOR]",
+                                    ast::SymbolKind::Destructor => "[DESTRUCTOR]",
+                                    ast::SymbolKind::Operator => "[OPERATOR]",
+                                    ast::SymbolKind::Field => "[FIELD]",
+                                    ast::SymbolKind::EnumMember => "[ENUM_MEMBER]",
+                                    ast::SymbolKind::Namespace => "[NAMESPACE]",
+                                    ast::SymbolKind::Property => "[PROPERTY]",
+                                    ast::SymbolKind::Event => "[EVENT]",
+                                    ast::SymbolKind::Module => "[MODULE]",
+                                    ast::SymbolKind::Constant => "[CONSTANT]",
+                                    ast::SymbolKind::Test => "[TEST]",
+                                    ast::SymbolKind::Import => "[IMPORT]",
+                                    ast::SymbolKind::Export => "[EXPORT]",
+                                    ast::SymbolKind::Impl => "[IMPL]",
+                                    ast::SymbolKind::Macro => "[MACRO]",
+                                    ast::SymbolKind::Section => "[SECTION]",
+                                    ast::SymbolKind::CodeBlock => "[CODE_BLOCK]",
+                                    ast::SymbolKind::Table => "[TABLE]",
+                                    ast::SymbolKind::View => "[VIEW]",
+                                    ast::SymbolKind::Index => "[INDEX]",
+                                    ast::SymbolKind::Selector => "[SELECTOR]",
+                                    ast::SymbolKind::Element => "[ELEMENT]",
                                 };
-                                println!("  {} {} (lines {}-{})", kind_name, symbol.name, symbol.start_line, symbol.end_line);
+                                let name = symbol.qualified_name.as_deref().unwrap_or(&symbol.name);
+                                let marker = if symbol.partial { " (partial)" } else if symbol.alteration { " (altered)" } else { "" };
+                                let cell = match (symbol.cell_index, symbol.cell_line) {
+                                    (Some(index), Some(line)) => format!(" [cell {}, line {}]", index, line),
+                                    _ => String::new(),
+                                };
+                                let condition = match &symbol.condition {
+                                    Some(condition) => format!(" [#if {}]", condition),
+                                    None => String::new(),
+                                };
+                                let implementation = match &symbol.implementation {
+                                    Some(class) => format!(" [implemented by {}]", class),
+                                    None => String::new(),
+                                };
+                                println!("  {} {}{} (lines {}-{}){}{}{}", kind_name, name, marker, symbol.start_line, symbol.end_line, cell, condition, implementation);
                             }
                             println!();
                         }
@@ -114,6 +228,30 @@ async fn main() -> Result<()> {
                 Err(e) => eprintln!("Error: {}", e),
             }
         }
+        Some(Commands::ApiReport { file_path, markdown }) => {
+            let req = ApiReportRequest { path_pattern: file_path, markdown: Some(markdown) };
+            match api_report(req).await {
+                Ok(report) => println!("{}", report),
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+        Some(Commands::ApiDiff { old, new, markdown }) => {
+            let diff = match (load_report(&old), load_report(&new)) {
+                (Ok(old), Ok(new)) => diff_reports(&old, &new),
+                (Err(e), _) | (_, Err(e)) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(2);
+                }
+            };
+            if markdown {
+                println!("{}", diff_markdown(&diff));
+            } else {
+                println!("{}", serde_json::to_string_pretty(&diff)?);
+            }
+            if !diff.breaking.is_empty() {
+                std::process::exit(1);
+            }
+        }
         Some(Commands::Mcp) => {
             tracing::info!("Starting MCP server");
             let server = ExtractSymbolsServer::new();
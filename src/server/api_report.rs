@@ -0,0 +1,460 @@
+//! Public API reports: the public and exported symbols of a package with
+//! their signatures and docs, grouped by module, and the differences
+//! between two such reports that break callers.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use crate::ast::{self, CodeParser, SymbolKind, Visibility};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Request structs
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ApiReportRequest {
+    pub path_pattern: String,
+    /// Render the report as Markdown instead of JSON.
+    pub markdown: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ApiDiffRequest {
+    /// Saved JSON report, or path pattern to report on, of the old version.
+    pub old: String,
+    /// Saved JSON report, or path pattern to report on, of the new version.
+    pub new: String,
+    /// Render the differences as Markdown instead of JSON.
+    pub markdown: Option<bool>,
+}
+
+// Response structs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ApiSymbol {
+    /// Qualified name, e.g. `Order.pay`.
+    pub name: String,
+    pub kind: SymbolKind,
+    pub visibility: Visibility,
+    /// Declaration up to its body, with whitespace collapsed.
+    pub signature: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ApiModule {
+    /// Path of the file, relative to the reported directory.
+    pub module: String,
+    pub symbols: Vec<ApiSymbol>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ApiReport {
+    pub modules: Vec<ApiModule>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiChangeKind {
+    Added,
+    Removed,
+    /// The signature changed; whether callers break depends on how, so
+    /// these are reported as breaking for a person to confirm.
+    SignatureChanged,
+    KindChanged,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ApiChange {
+    pub module: String,
+    pub name: String,
+    pub change: ApiChangeKind,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub old: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub new: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ApiDiff {
+    pub breaking: Vec<ApiChange>,
+    pub additions: Vec<ApiChange>,
+}
+
+/// Symbols that are not part of an API even when public: `__all__` and its
+/// entries repeat the definitions they name, and `impl` blocks have no
+/// visibility of their own.
+fn is_api_symbol(symbol: &ast::symbol::Symbol) -> bool {
+    let excluded = matches!(
+        symbol.kind,
+        SymbolKind::Export | SymbolKind::Import | SymbolKind::Impl | SymbolKind::Section | SymbolKind::CodeBlock
+    );
+    !excluded && symbol.name != "__all__"
+}
+
+/// Directory that module paths are relative to: the directory itself, the
+/// parent of a single file, or the part of a glob before its first pattern.
+fn report_root(path_pattern: &str) -> PathBuf {
+    let path = Path::new(path_pattern);
+    if path.is_dir() {
+        return path.to_path_buf();
+    }
+    if path.is_file() {
+        return path.parent().map(Path::to_path_buf).unwrap_or_default();
+    }
+    path.components()
+        .take_while(|c| !c.as_os_str().to_string_lossy().contains(['*', '?', '[', '{']))
+        .collect()
+}
+
+/// Decorators and attributes (`@app.get`, `#[derive]`, `[[nodiscard]]`)
+/// that open a declaration.
+fn is_decorator_line(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with('@') || line.starts_with("#[") || line.starts_with("[[")
+}
+
+/// Cut an initializer (`= 10`) from a declaration, so that changing the
+/// value of a constant or the default of a field is not a signature change.
+fn without_initializer(text: &str) -> &str {
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth -= 1,
+            '=' if depth <= 0 && text[i..].starts_with("= ") && text[..i].ends_with(' ') => {
+                return text[..i].trim_end();
+            }
+            _ => {}
+        }
+    }
+    text
+}
+
+/// The declaration of `symbol` up to its body (or its first line when it
+/// has none), without decorators, comments or initializer and with
+/// whitespace collapsed: `def pay(self, amount: int) -> Receipt` or `pub fn
+/// new() -> Self`.
+fn signature(source: &str, symbol: &ast::symbol::Symbol, markers: &[&str]) -> String {
+    let end = match &symbol.body_range {
+        Some(body) if body.start >= symbol.full_range.start => body.start,
+        _ => symbol.full_range.end,
+    };
+    // Notebook symbols have ranges within their cell, not the file
+    let Some(text) = source.get(symbol.full_range.start..end) else {
+        return String::new();
+    };
+    let lines: Vec<&str> = text.lines().collect();
+    let mut lines = lines[leading_trivia(&lines, markers)..].iter().map(|line| line.trim());
+    let text = if symbol.body_range.is_some() {
+        lines.collect::<Vec<_>>().join(" ")
+    } else {
+        without_initializer(lines.next().unwrap_or_default()).to_string()
+    };
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    text.trim_end_matches(['{', ':', '=', ';', ',']).trim_end().to_string()
+}
+
+/// Strip the comment markers of a doc comment line, `/// Returns` or
+/// ` * Returns` to `Returns`.
+fn strip_comment_marker(line: &str) -> &str {
+    let line = line.trim();
+    let line = line.strip_suffix("*/").unwrap_or(line);
+    for marker in ["///", "//!", "//", "/**", "/*", "*", "#", "--"] {
+        if let Some(rest) = line.strip_prefix(marker) {
+            return rest.trim();
+        }
+    }
+    line
+}
+
+/// Markers starting a line comment in files with `extension`.
+fn line_comment_markers(extension: &str) -> &'static [&'static str] {
+    match extension {
+        "sql" | "lua" | "hs" => &["--"],
+        // Extensionless files are scripts, Dockerfiles and Makefiles
+        "" | "rb" | "sh" | "bash" | "ex" | "exs" | "tf" | "hcl" | "graphql" | "gql" => &["#"],
+        "php" | "thrift" => &["//", "#"],
+        _ => &["//"],
+    }
+}
+
+fn is_line_comment(line: &str, markers: &[&str]) -> bool {
+    let line = line.trim_start();
+    markers.iter().any(|marker| line.starts_with(marker)) && !line.starts_with("#[")
+}
+
+/// Whether a `/*` comment opening `line` continues on the next line.
+fn opens_block(line: &str) -> bool {
+    line.starts_with("/*") && !line[2..].contains("*/")
+}
+
+/// Number of leading `lines` that are blank, decorators or comments, such
+/// as the doc comments Rust allows between attributes.
+fn leading_trivia(lines: &[&str], markers: &[&str]) -> usize {
+    let mut in_block = false;
+    lines.iter()
+        .take_while(|line| {
+            let line = line.trim();
+            if in_block {
+                in_block = !line.contains("*/");
+                return true;
+            }
+            in_block = opens_block(line);
+            line.is_empty() || line.starts_with("/*") || is_decorator_line(line) || is_line_comment(line, markers)
+        })
+        .count()
+}
+
+/// The comment lines directly above `before`'s end: line comments, and
+/// whole `/* … */` blocks, whose `*` continuation lines only count once
+/// the closing `*/` has been seen.
+fn trailing_comment<'a>(before: &'a str, markers: &[&str]) -> Vec<&'a str> {
+    let mut lines = Vec::new();
+    let mut block_start = None;
+    for line in before.lines().rev() {
+        let trimmed = line.trim();
+        // A block opened after code on its line is a remark, not a doc
+        let after_code = !trimmed.starts_with("/*") && trimmed.contains("/*");
+        if let Some(start) = block_start {
+            if after_code {
+                lines.truncate(start);
+                break;
+            }
+            lines.push(line);
+            if trimmed.starts_with("/*") {
+                block_start = None;
+            }
+        } else if trimmed.ends_with("*/") {
+            if after_code {
+                break;
+            }
+            if !trimmed.starts_with("/*") {
+                block_start = Some(lines.len());
+            }
+            lines.push(line);
+        } else if is_line_comment(trimmed, markers) {
+            lines.push(line);
+        } else {
+            break;
+        }
+    }
+    // An unopened block reaches back to the start of the file
+    if let Some(start) = block_start {
+        lines.truncate(start);
+    }
+    lines.reverse();
+    lines
+}
+
+/// Python docstring: a string literal opening the body.
+fn docstring(source: &str, symbol: &ast::symbol::Symbol) -> Option<String> {
+    let body = source.get(symbol.body_range.clone()?)?.trim_start();
+    let body = body.trim_start_matches(['r', 'R', 'u', 'U']);
+    let quote = ["\"\"\"", "'''", "\"", "'"].into_iter().find(|q| body.starts_with(q))?;
+    let content = &body[quote.len()..];
+    let text = &content[..content.find(quote)?];
+    let lines: Vec<_> = text.lines().map(str::trim).collect();
+    Some(lines.join("\n").trim().to_string())
+}
+
+/// The comment directly above the declaration, or the docstring of a
+/// Python definition.
+fn doc(source: &str, symbol: &ast::symbol::Symbol, extension: &str) -> Option<String> {
+    if extension == "py" {
+        return docstring(source, symbol).filter(|doc| !doc.is_empty());
+    }
+
+    let markers = line_comment_markers(extension);
+    let before = source.get(..symbol.full_range.start)?;
+    let before = &before[..before.rfind('\n').map_or(0, |i| i + 1)];
+    let mut lines = trailing_comment(before, markers);
+
+    // Doc comments may also sit between the attributes of a declaration
+    let text: Vec<&str> = source.get(symbol.full_range.clone())?.lines().collect();
+    lines.extend(text[..leading_trivia(&text, markers)].iter()
+        .filter(|line| !line.trim().is_empty() && !is_decorator_line(line)));
+
+    let lines: Vec<&str> = lines.into_iter().map(strip_comment_marker).collect();
+    let doc = lines.join("\n").trim().to_string();
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// Report on the public API of the files matching `path_pattern`.
+pub fn build_report(path_pattern: &str) -> Result<ApiReport, String> {
+    let root = report_root(path_pattern);
+    let mut modules = Vec::new();
+
+    for file_path in crate::server::resolve_file_paths(path_pattern)? {
+        let Some(mut parser) = CodeParser::for_file(&file_path) else {
+            continue;
+        };
+        let Ok(source) = fs::read_to_string(&file_path) else {
+            continue;
+        };
+        parser.set_public_only(true);
+        let Ok(symbols) = parser.extract_symbols(&source, &file_path.to_string_lossy(), None) else {
+            continue;
+        };
+
+        let extension = file_path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+        let symbols: Vec<ApiSymbol> = symbols.iter()
+            .filter(|s| is_api_symbol(s))
+            .filter_map(|s| {
+                // Only languages that declare visibility have an API to report
+                let visibility = s.visibility.filter(|v| v.is_public())?;
+                Some(ApiSymbol {
+                    name: s.qualified_name.clone().unwrap_or_else(|| s.name.clone()),
+                    kind: s.kind,
+                    visibility,
+                    signature: signature(&source, s, line_comment_markers(extension)),
+                    doc: doc(&source, s, extension),
+                    line: s.start_line,
+                })
+            })
+            .collect();
+
+        if !symbols.is_empty() {
+            let module = file_path.strip_prefix(&root).unwrap_or(&file_path);
+            modules.push(ApiModule {
+                module: module.to_string_lossy().replace('\\', "/"),
+                symbols,
+            });
+        }
+    }
+
+    modules.sort_by(|a, b| a.module.cmp(&b.module));
+    Ok(ApiReport { modules })
+}
+
+/// A saved JSON report, or a fresh report on a path pattern.
+pub fn load_report(source: &str) -> Result<ApiReport, String> {
+    let path = Path::new(source);
+    if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+        let content = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", source, e))?;
+        return serde_json::from_str(&content).map_err(|e| format!("Invalid API report {}: {}", source, e));
+    }
+    build_report(source)
+}
+
+/// Symbols by module and name; overloads share a name and are compared as
+/// a set of signatures.
+fn index(report: &ApiReport) -> BTreeMap<(String, String), (SymbolKind, BTreeSet<String>)> {
+    let mut index: BTreeMap<_, (SymbolKind, BTreeSet<String>)> = BTreeMap::new();
+    for module in &report.modules {
+        for symbol in &module.symbols {
+            index.entry((module.module.clone(), symbol.name.clone()))
+                .or_insert_with(|| (symbol.kind, BTreeSet::new()))
+                .1
+                .insert(symbol.signature.clone());
+        }
+    }
+    index
+}
+
+/// Changes from `old` to `new`. Removed symbols, changed kinds and changed
+/// signatures break callers; added symbols do not. Docs are not compared.
+pub fn diff_reports(old: &ApiReport, new: &ApiReport) -> ApiDiff {
+    let (old, new) = (index(old), index(new));
+    let mut diff = ApiDiff { breaking: Vec::new(), additions: Vec::new() };
+
+    for ((module, name), (old_kind, old_signatures)) in &old {
+        let change = |change, new: &BTreeSet<String>| ApiChange {
+            module: module.clone(),
+            name: name.clone(),
+            change,
+            old: old_signatures.iter().cloned().collect(),
+            new: new.iter().cloned().collect(),
+        };
+        match new.get(&(module.clone(), name.clone())) {
+            None => diff.breaking.push(change(ApiChangeKind::Removed, &BTreeSet::new())),
+            Some((new_kind, new_signatures)) if new_kind != old_kind => {
+                diff.breaking.push(change(ApiChangeKind::KindChanged, new_signatures));
+            }
+            Some((_, new_signatures)) if !old_signatures.is_subset(new_signatures) => {
+                diff.breaking.push(change(ApiChangeKind::SignatureChanged, new_signatures));
+            }
+            Some(_) => {}
+        }
+    }
+    for ((module, name), (_, new_signatures)) in &new {
+        if !old.contains_key(&(module.clone(), name.clone())) {
+            diff.additions.push(ApiChange {
+                module: module.clone(),
+                name: name.clone(),
+                change: ApiChangeKind::Added,
+                old: Vec::new(),
+                new: new_signatures.iter().cloned().collect(),
+            });
+        }
+    }
+    diff
+}
+
+fn kind_label(kind: SymbolKind) -> String {
+    serde_json::to_value(kind)
+        .ok()
+        .and_then(|value| value.as_str().map(|s| s.replace('_', " ")))
+        .unwrap_or_default()
+}
+
+pub fn report_markdown(report: &ApiReport) -> String {
+    let mut out = String::from("# Public API\n");
+    for module in &report.modules {
+        let _ = write!(out, "\n## `{}`\n", module.module);
+        for symbol in &module.symbols {
+            let _ = write!(out, "\n### `{}` ({})\n\n```\n{}\n```\n", symbol.name, kind_label(symbol.kind), symbol.signature);
+            if let Some(doc) = &symbol.doc {
+                let _ = write!(out, "\n{}\n", doc);
+            }
+        }
+    }
+    out
+}
+
+pub fn diff_markdown(diff: &ApiDiff) -> String {
+    let mut out = String::from("# API changes\n");
+    let sections = [("Breaking changes", &diff.breaking), ("Additions", &diff.additions)];
+    for (title, changes) in sections {
+        let _ = write!(out, "\n## {}\n\n", title);
+        if changes.is_empty() {
+            out.push_str("None.\n");
+        }
+        for change in changes {
+            let what = match change.change {
+                ApiChangeKind::Added => "added",
+                ApiChangeKind::Removed => "removed",
+                ApiChangeKind::SignatureChanged => "signature changed",
+                ApiChangeKind::KindChanged => "kind changed",
+            };
+            let _ = writeln!(out, "- `{}` in `{}`: {}", change.name, change.module, what);
+            for signature in &change.old {
+                let _ = writeln!(out, "  - old: `{}`", signature);
+            }
+            for signature in &change.new {
+                let _ = writeln!(out, "  - new: `{}`", signature);
+            }
+        }
+    }
+    out
+}
+
+// Handler functions
+pub async fn api_report(params: ApiReportRequest) -> Result<String, String> {
+    let report = build_report(&params.path_pattern)?;
+    if params.markdown.unwrap_or(false) {
+        Ok(report_markdown(&report))
+    } else {
+        serde_json::to_string_pretty(&report).map_err(|e| e.to_string())
+    }
+}
+
+pub async fn api_diff(params: ApiDiffRequest) -> Result<String, String> {
+    let diff = diff_reports(&load_report(&params.old)?, &load_report(&params.new)?);
+    if params.markdown.unwrap_or(false) {
+        Ok(diff_markdown(&diff))
+    } else {
+        serde_json::to_string_pretty(&diff).map_err(|e| e.to_string())
+    }
+}
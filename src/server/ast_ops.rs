@@ -11,6 +11,23 @@ pub struct ExtractSymbolsRequest {
     pub filter: Option<ast::SymbolKind>,
     pub start_line: Option<usize>,
     pub end_line: Option<usize>,
+    /// Also extract symbols from Markdown code fences in supported languages.
+    pub code_fences: Option<bool>,
+    /// Also report the headings of Markdown cells in Jupyter notebooks.
+    pub notebook_headings: Option<bool>,
+    /// Also report variables assigned inside Python functions.
+    pub python_locals: Option<bool>,
+    /// Only return symbols with this decorator, annotation or attribute,
+    /// e.g. `pytest.fixture`, `app.get` or `test`; arguments are ignored.
+    pub decorator: Option<String>,
+    /// Only return the public API surface: symbols that are public or
+    /// exported, outside private containers.
+    pub public_only: Option<bool>,
+    /// Macros defined for a C or C++ build, `NAME` or `NAME=VALUE`; symbols
+    /// in `#if` branches that the build leaves out are skipped.
+    pub defines: Option<Vec<String>>,
+    /// Parse `.h` files as C or C++ rather than guessing from their content.
+    pub header_language: Option<ast::HeaderLanguage>,
 }
 
 // Response structs
@@ -20,6 +37,28 @@ pub struct Symbol {
     pub kind: ast::SymbolKind,
     pub start_line: usize,
     pub end_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qualified_name: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub partial: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub alteration: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cell_index: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cell_line: Option<usize>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ast::Visibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implementation: Option<String>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -36,39 +75,55 @@ pub async fn extract_symbols(params: ExtractSymbolsRequest) -> Result<Vec<FileSy
     for file_path in file_paths {
         let file_path_str = file_path.to_string_lossy().to_string();
         
-        // Only process files that have supported language extensions
-        if let Some(language) = ast::get_language(&file_path_str) {
-            if let Ok(content) = fs::read_to_string(&file_path) {
-                if let Ok(mut parser) = ast::CodeParser::new(language) {
-                    let filter = params.filter.map(|kind| {
-                        let mut set = std::collections::HashSet::new();
-                        set.insert(kind);
-                        set
-                    });
+        // Only process files in supported formats, including extensionless
+        // scripts whose shebang names a supported interpreter
+        if let Some(mut parser) = ast::CodeParser::for_file(&file_path)
+            && let Ok(content) = fs::read_to_string(&file_path)
+        {
+            parser.set_code_fences(params.code_fences.unwrap_or(false));
+            parser.set_notebook_headings(params.notebook_headings.unwrap_or(false));
+            parser.set_python_locals(params.python_locals.unwrap_or(false));
+            parser.set_public_only(params.public_only.unwrap_or(false));
+            parser.set_defines(params.defines.as_deref());
+            parser.set_header_language(params.header_language);
+            let filter = params.filter.map(|kind| {
+                let mut set = std::collections::HashSet::new();
+                set.insert(kind);
+                set
+            });
 
-                    if let Ok(ast_symbols) = parser.extract_symbols(&content, &file_path_str, filter) {
-                        let symbols: Vec<Symbol> = ast_symbols.into_iter()
-                            .filter(|s| {
-                                let in_range = match (params.start_line, params.end_line) {
-                                    (Some(start), Some(end)) => s.start_line >= start && s.start_line <= end,
-                                    (Some(start), None) => s.start_line >= start,
-                                    (None, Some(end)) => s.start_line <= end,
-                                    (None, None) => true,
-                                };
-                                in_range
-                            })
-                            .map(|s| Symbol {
-                                name: s.name,
-                                kind: s.kind,
-                                start_line: s.start_line,
-                                end_line: s.end_line,
-                            })
-                            .collect();
-                        
-                        if !symbols.is_empty() {
-                            file_symbols_map.insert(file_path_str, symbols);
+            if let Ok(ast_symbols) = parser.extract_symbols(&content, &file_path_str, filter) {
+                let symbols: Vec<Symbol> = ast_symbols.into_iter()
+                    .filter(|s| {
+                        match (params.start_line, params.end_line) {
+                            (Some(start), Some(end)) => s.start_line >= start && s.start_line <= end,
+                            (Some(start), None) => s.start_line >= start,
+                            (None, Some(end)) => s.start_line <= end,
+                            (None, None) => true,
                         }
-                    }
+                    })
+                    .filter(|s| params.decorator.as_deref().is_none_or(|decorator| s.has_decorator(decorator)))
+                    .map(|s| Symbol {
+                        name: s.name,
+                        kind: s.kind,
+                        start_line: s.start_line,
+                        end_line: s.end_line,
+                        container: s.container,
+                        qualified_name: s.qualified_name,
+                        partial: s.partial,
+                        alteration: s.alteration,
+                        cell_index: s.cell_index,
+                        cell_line: s.cell_line,
+                        decorators: s.decorators,
+                        visibility: s.visibility,
+                        type_parameters: s.type_parameters,
+                        condition: s.condition,
+                        implementation: s.implementation,
+                    })
+                    .collect();
+
+                if !symbols.is_empty() {
+                    file_symbols_map.insert(file_path_str, symbols);
                 }
             }
         }
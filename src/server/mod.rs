@@ -1,6 +1,11 @@
 pub mod ast_ops;
+pub mod api_report;
 
 pub use ast_ops::*;
+pub use api_report::*;
+
+#[cfg(test)]
+mod tests_api_report;
 
 use glob::glob;
 use globwalk::glob as globwalk_glob;
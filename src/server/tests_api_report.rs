@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use super::super::api_report::*;
+    use crate::ast::SymbolKind;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// A fresh directory holding `files`, unique to the calling test.
+    fn write_package(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("fs_query_api_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (file, content) in files {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_api_report() {
+        let dir = write_package("report", &[
+            ("orders/models.py", r#"
+__all__ = ["Order"]
+
+class Order:
+    """A customer order."""
+
+    def pay(self, amount: int,
+            currency: str = "EUR") -> bool:
+        """Charge the customer."""
+        return True
+
+    def _audit(self):
+        pass
+
+def helper():
+    pass
+"#),
+            ("lib.rs", r#"
+/// Creates an order.
+pub fn create(id: u64) -> Order {
+    todo!()
+}
+
+fn internal() {}
+"#),
+            ("notes.txt", "not code"),
+        ]);
+
+        let report = build_report(dir.to_str().unwrap()).unwrap();
+        let modules: Vec<_> = report.modules.iter().map(|m| m.module.as_str()).collect();
+        assert_eq!(modules, vec!["lib.rs", "orders/models.py"]);
+
+        let create = &report.modules[0].symbols[0];
+        assert_eq!(create.name, "create");
+        assert_eq!(create.signature, "pub fn create(id: u64) -> Order");
+        assert_eq!(create.doc.as_deref(), Some("Creates an order."));
+        assert_eq!(report.modules[0].symbols.len(), 1);
+
+        let symbols: Vec<_> = report.modules[1].symbols.iter()
+            .map(|s| (s.name.as_str(), s.kind, s.signature.as_str(), s.doc.as_deref()))
+            .collect();
+        assert_eq!(symbols, vec![
+            ("Order", SymbolKind::Class, "class Order", Some("A customer order.")),
+            ("Order.pay", SymbolKind::Function, "def pay(self, amount: int, currency: str = \"EUR\") -> bool", Some("Charge the customer.")),
+        ]);
+
+        let markdown = report_markdown(&report);
+        assert!(markdown.contains("## `orders/models.py`"));
+        assert!(markdown.contains("### `Order.pay` (function)"));
+    }
+
+    #[test]
+    fn test_api_report_docs() {
+        // Lines of code that start like comments are not docs
+        let dir = write_package("docs", &[("orders.cpp", r#"
+int total,
+    *cursor;
+int open_orders(void);
+
+#define DEC(x) \
+    --(x)
+int close_orders(void);
+
+/*
+ * Ships an order.
+ */
+int ship(int id);
+"#)]);
+
+        let report = build_report(dir.to_str().unwrap()).unwrap();
+        let docs: Vec<_> = report.modules[0].symbols.iter()
+            .filter(|s| s.kind == SymbolKind::Function)
+            .map(|s| (s.name.as_str(), s.doc.as_deref()))
+            .collect();
+        assert_eq!(docs, vec![
+            ("open_orders", None),
+            ("close_orders", None),
+            ("ship", Some("Ships an order.")),
+        ]);
+    }
+
+    #[test]
+    fn test_api_diff() {
+        let old_dir = write_package("old", &[("api.go", r#"
+package api
+
+func Open(path string) error { return nil }
+
+func Close() {}
+
+type Handle struct{}
+"#)]);
+        let new_dir = write_package("new", &[("api.go", r#"
+package api
+
+// Open now takes flags.
+func Open(path string, flags int) error { return nil }
+
+type Handle interface{}
+
+func Sync() {}
+"#)]);
+
+        let old = build_report(old_dir.to_str().unwrap()).unwrap();
+        let new = build_report(new_dir.to_str().unwrap()).unwrap();
+        let diff = diff_reports(&old, &new);
+
+        let breaking: Vec<_> = diff.breaking.iter()
+            .map(|c| (c.name.as_str(), c.change))
+            .collect();
+        assert_eq!(breaking, vec![
+            ("Close", ApiChangeKind::Removed),
+            ("Handle", ApiChangeKind::KindChanged),
+            ("Open", ApiChangeKind::SignatureChanged),
+        ]);
+        let open = &diff.breaking[2];
+        assert_eq!(open.old, vec!["func Open(path string) error"]);
+        assert_eq!(open.new, vec!["func Open(path string, flags int) error"]);
+
+        let additions: Vec<_> = diff.additions.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(additions, vec!["Sync"]);
+
+        // A report saved as JSON diffs the same as a fresh one
+        let saved = old_dir.join("report.json");
+        fs::write(&saved, serde_json::to_string(&old).unwrap()).unwrap();
+        assert_eq!(load_report(saved.to_str().unwrap()).unwrap(), old);
+        assert!(diff_reports(&new, &new).breaking.is_empty());
+
+        let markdown = diff_markdown(&diff);
+        assert!(markdown.contains("- `Close` in `api.go`: removed"));
+    }
+}